@@ -0,0 +1,44 @@
+use utils::{Error, Puzzle, Result};
+
+#[derive(Debug, Clone, Copy)]
+enum Move {
+    Up = 1,
+    Down = -1,
+}
+
+struct Day1 {
+    moves: Vec<Move>,
+}
+
+impl Puzzle<isize, usize> for Day1 {
+    fn from_string(s: String) -> Result<Self> {
+        let moves = s
+            .trim_end()
+            .chars()
+            .enumerate()
+            .map(|(i, m)| match m {
+                '(' => Ok(Move::Up),
+                ')' => Ok(Move::Down),
+                _ => Err(Error::parse(1, i + 1, format!("unknown move {:?}", m))),
+            })
+            .collect::<Result<Vec<Move>>>()?;
+        Ok(Self { moves })
+    }
+
+    fn solve1(&self) -> Result<isize> {
+        Ok(self.moves.iter().fold(0, move |acc, m| acc + *m as isize))
+    }
+
+    fn solve2(&self) -> Result<usize> {
+        let mut floor = 0;
+        for (i, m) in self.moves.iter().enumerate() {
+            floor += *m as isize;
+            if floor == -1 {
+                return Ok(i + 1);
+            }
+        }
+        Err(Error::no_solution("Santa never enters the basement"))
+    }
+}
+
+utils::solution!(2015, 1, Day1);
@@ -1,4 +1,4 @@
-use utils::Puzzle;
+use utils::{Puzzle, Result};
 
 struct Present {
     length: usize,
@@ -47,48 +47,49 @@ impl Present {
     }
 }
 
+impl std::str::FromStr for Present {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let dim = s
+            .split('x')
+            .map(|n| n.parse::<usize>().map_err(|err| err.to_string()))
+            .collect::<Result<Vec<usize>, _>>()?;
+        match dim[..] {
+            [length, width, height] => Ok(Self {
+                length,
+                width,
+                height,
+            }),
+            _ => Err(format!("expected 3 dimensions in {:?}", s)),
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Day2 {
     presents: Vec<Present>,
 }
 
 impl Puzzle<usize, usize> for Day2 {
-    fn from_string(s: String) -> Self {
-        let presents: Vec<Present> = s
-            .lines()
-            .map(|line| {
-                let dim: Vec<usize> = line.split('x').map(|n| n.parse().unwrap()).collect();
-                Present {
-                    length: *dim.first().unwrap(),
-                    width: *dim.get(1).unwrap(),
-                    height: *dim.get(2).unwrap(),
-                }
-            })
-            .collect();
-        Self { presents }
+    fn from_string(s: String) -> Result<Self> {
+        let presents = utils::parse_lines(&s, str::parse::<Present>)?;
+        Ok(Self { presents })
     }
 
-    fn solve1(&self) -> usize {
-        self.presents
+    fn solve1(&self) -> Result<usize> {
+        Ok(self
+            .presents
             .iter()
-            .fold(0, move |acc, present| acc + present.papper())
+            .fold(0, move |acc, present| acc + present.papper()))
     }
 
-    fn solve2(&self) -> usize {
-        self.presents
+    fn solve2(&self) -> Result<usize> {
+        Ok(self
+            .presents
             .iter()
-            .fold(0, move |acc, present| acc + present.ribbon())
+            .fold(0, move |acc, present| acc + present.ribbon()))
     }
 }
 
-fn main() {
-    let puzzle = Day2::from_file();
-
-    let part1 = puzzle.solve1();
-    println!("Part 1: answer is {}.", part1);
-    assert_eq!(part1, 1586300);
-
-    let part2 = puzzle.solve2();
-    println!("Part 2: answer is {}.", part2);
-    assert_eq!(part2, 3737498);
-}
+utils::solution!(2015, 2, Day2);
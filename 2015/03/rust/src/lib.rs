@@ -0,0 +1,45 @@
+use {
+    std::collections::HashSet,
+    utils::{Direction, Error, Point, Puzzle, Result, Step},
+};
+
+#[derive(Debug)]
+struct Day3 {
+    moves: Vec<Direction>,
+}
+
+impl Day3 {
+    fn dispatch(&self, santas: usize) -> usize {
+        assert!(santas > 0);
+        let mut visitors = vec![Point::default(); santas];
+        let mut houses = HashSet::from([Point::default()]);
+        for (i, direction) in self.moves.iter().enumerate() {
+            let visitor = &mut visitors[i % santas];
+            *visitor += Step(*direction, 1);
+            houses.insert(*visitor);
+        }
+        houses.len()
+    }
+}
+
+impl Puzzle<usize, usize> for Day3 {
+    fn from_string(s: String) -> Result<Self> {
+        let moves = s
+            .trim_end()
+            .chars()
+            .enumerate()
+            .map(|(i, c)| Direction::try_from(c).map_err(|err| Error::parse(1, i + 1, err)))
+            .collect::<Result<Vec<Direction>>>()?;
+        Ok(Self { moves })
+    }
+
+    fn solve1(&self) -> Result<usize> {
+        Ok(self.dispatch(1))
+    }
+
+    fn solve2(&self) -> Result<usize> {
+        Ok(self.dispatch(2))
+    }
+}
+
+utils::solution!(2015, 3, Day3);
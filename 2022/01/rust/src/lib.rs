@@ -0,0 +1,49 @@
+use utils::{Error, Puzzle, Result};
+
+struct Day1 {
+    cal: Vec<usize>,
+}
+
+impl Day1 {
+    fn desc_cum_sum(&self, len: usize) -> usize {
+        {
+            let mut cal: Vec<usize> = self.cal.clone();
+            cal.sort_by(|a, b| b.cmp(a));
+            cal.truncate(len);
+            cal
+        }
+        .iter()
+        .sum()
+    }
+}
+
+impl Puzzle for Day1 {
+    fn from_string(s: String) -> Result<Self> {
+        let mut cal: Vec<usize> = vec![0];
+        for (i, line) in s.lines().enumerate() {
+            if line.is_empty() {
+                cal.push(0);
+            } else {
+                let food = line
+                    .parse::<usize>()
+                    .map_err(|err| Error::parse(i + 1, 1, err))?;
+                let elf = cal.last_mut().unwrap();
+                *elf = elf
+                    .checked_add(food)
+                    .ok_or_else(|| Error::parse(i + 1, 1, "too many calories"))?;
+            }
+        }
+
+        Ok(Self { cal })
+    }
+
+    fn solve1(&self) -> Result<usize> {
+        Ok(self.desc_cum_sum(1))
+    }
+
+    fn solve2(&self) -> Result<usize> {
+        Ok(self.desc_cum_sum(3))
+    }
+}
+
+utils::solution!(2022, 1, Day1);
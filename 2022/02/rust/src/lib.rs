@@ -1,13 +1,4 @@
-use utils::Puzzle;
-
-#[derive(Debug, thiserror::Error)]
-pub enum DayTwoError {
-    #[error("Failed to parse shape: {0}.")]
-    Shape(String),
-
-    #[error("Failed to get 2 shapes from: {0}.")]
-    Game(String),
-}
+use utils::{Puzzle, Result};
 
 #[derive(Debug, Clone, Copy)]
 #[repr(usize)]
@@ -18,27 +9,27 @@ enum Shape {
 }
 
 impl TryFrom<usize> for Shape {
-    type Error = DayTwoError;
+    type Error = String;
 
     fn try_from(u: usize) -> Result<Self, Self::Error> {
         match u {
             1 => Ok(Self::Rock),
             2 => Ok(Self::Paper),
             3 => Ok(Self::Scissor),
-            _ => Err(DayTwoError::Shape(u.to_string())),
+            _ => Err(format!("unknown shape {}", u)),
         }
     }
 }
 
 impl std::str::FromStr for Shape {
-    type Err = DayTwoError;
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "A" | "X" => Ok(Self::Rock),
             "B" | "Y" => Ok(Self::Paper),
             "C" | "Z" => Ok(Self::Scissor),
-            _ => Err(DayTwoError::Shape(s.into())),
+            _ => Err(format!("unknown shape {:?}", s)),
         }
     }
 }
@@ -115,7 +106,7 @@ impl Game {
 }
 
 impl std::str::FromStr for Game {
-    type Err = DayTwoError;
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let shapes: Vec<&str> = s.split(' ').collect();
@@ -124,7 +115,7 @@ impl std::str::FromStr for Game {
                 shapes[0].parse::<Shape>()?,
                 shapes[1].parse::<Shape>()?,
             )),
-            _ => Err(DayTwoError::Game(s.into())),
+            _ => Err(format!("expected 2 shapes in {:?}", s)),
         }
     }
 }
@@ -135,36 +126,25 @@ struct Day2 {
 }
 
 impl Puzzle for Day2 {
-    fn from_string(s: String) -> Self {
-        let games: Vec<Game> = s
-            .lines()
-            .map(|line| line.parse::<Game>().unwrap())
-            .collect();
+    fn from_string(s: String) -> Result<Self> {
+        let games = utils::parse_lines(&s, str::parse::<Game>)?;
 
-        Self { games }
+        Ok(Self { games })
     }
 
-    fn solve1(&self) -> usize {
-        self.games
+    fn solve1(&self) -> Result<usize> {
+        Ok(self
+            .games
             .iter()
-            .fold(0, |acc, game| acc + game.play_strategy())
+            .fold(0, |acc, game| acc + game.play_strategy()))
     }
 
-    fn solve2(&self) -> usize {
-        self.games
+    fn solve2(&self) -> Result<usize> {
+        Ok(self
+            .games
             .iter()
-            .fold(0, |acc, game| acc + game.play_outcome())
+            .fold(0, |acc, game| acc + game.play_outcome()))
     }
 }
 
-fn main() {
-    let puzzle = Day2::from_file();
-
-    let part1 = puzzle.solve1();
-    println!("Part 1: answer is {}.", part1);
-    assert_eq!(part1, 13009);
-
-    let part2 = puzzle.solve2();
-    println!("Part 2: answer is {}.", part2);
-    assert_eq!(part2, 10398);
-}
+utils::solution!(2022, 2, Day2);
@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use utils::Puzzle;
+use utils::{Error, Puzzle, Result};
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 struct Item(char);
@@ -24,13 +24,17 @@ impl Compartiment {
 }
 
 impl std::str::FromStr for Compartiment {
-    type Err = ();
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let items = s
-            .split("")
-            .filter_map(|item| item.chars().next().map(Item))
-            .collect();
+            .chars()
+            .map(|item| {
+                item.is_ascii_alphabetic()
+                    .then_some(Item(item))
+                    .ok_or_else(|| format!("unknown item {:?}", item))
+            })
+            .collect::<Result<_, _>>()?;
         Ok(Self(items))
     }
 }
@@ -58,7 +62,7 @@ impl CommonItem for Rucksack {
 }
 
 impl std::str::FromStr for Rucksack {
-    type Err = ();
+    type Err = String;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let mid = s.len() / 2;
@@ -97,38 +101,35 @@ impl Day3 {
 }
 
 impl Puzzle for Day3 {
-    fn from_string(s: String) -> Self {
-        let rucksacks: Vec<Rucksack> = s
-            .lines()
-            .map(|line| line.parse::<Rucksack>().unwrap())
-            .collect();
+    fn from_string(s: String) -> Result<Self> {
+        let rucksacks = utils::parse_lines(&s, str::parse::<Rucksack>)?;
 
-        Self(rucksacks)
+        Ok(Self(rucksacks))
     }
 
-    fn solve1(&self) -> usize {
+    fn solve1(&self) -> Result<usize> {
         self.0
             .iter()
-            .map(|rucksack| rucksack.common_item().unwrap().priority())
+            .map(|rucksack| {
+                rucksack
+                    .common_item()
+                    .map(|item| item.priority())
+                    .ok_or_else(|| Error::no_solution("rucksack without misplaced item"))
+            })
             .sum()
     }
 
-    fn solve2(&self) -> usize {
+    fn solve2(&self) -> Result<usize> {
         self.dispatch()
             .iter()
-            .map(|group| group.common_item().unwrap().priority())
+            .map(|group| {
+                group
+                    .common_item()
+                    .map(|item| item.priority())
+                    .ok_or_else(|| Error::no_solution("group without badge"))
+            })
             .sum()
     }
 }
 
-fn main() {
-    let puzzle = Day3::from_file();
-
-    let part1 = puzzle.solve1();
-    println!("Part 1: answer is {}.", part1,);
-    assert_eq!(part1, 7737);
-
-    let part2 = puzzle.solve2();
-    println!("Part 2: answer is {}.", part2,);
-    assert_eq!(part2, 2697);
-}
+utils::solution!(2022, 3, Day3);
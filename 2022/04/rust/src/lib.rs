@@ -0,0 +1,72 @@
+use utils::{Puzzle, Result};
+
+#[derive(Debug)]
+struct Section(std::ops::RangeInclusive<usize>);
+
+impl std::str::FromStr for Section {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b) = s
+            .split_once('-')
+            .ok_or_else(|| format!("expected a range in {:?}", s))?;
+        let a = a.parse::<usize>().map_err(|err| err.to_string())?;
+        let b = b.parse::<usize>().map_err(|err| err.to_string())?;
+        Ok(Section(a..=b))
+    }
+}
+
+impl Section {
+    fn contains(&self, other: &Section) -> bool {
+        self.0.start() <= other.0.start() && self.0.end() >= other.0.end()
+    }
+
+    fn overlaps(&self, other: &Section) -> bool {
+        self.0.start() <= other.0.end() && self.0.end() >= other.0.start()
+    }
+}
+
+#[derive(Debug)]
+struct Pair(Section, Section);
+
+impl std::str::FromStr for Pair {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (a, b) = s
+            .split_once(',')
+            .ok_or_else(|| format!("expected a pair in {:?}", s))?;
+        Ok(Pair(a.parse::<Section>()?, b.parse::<Section>()?))
+    }
+}
+
+impl Pair {
+    fn has_full_overlap(&self) -> bool {
+        self.0.contains(&self.1) || self.1.contains(&self.0)
+    }
+
+    fn has_overlap(&self) -> bool {
+        self.0.overlaps(&self.1)
+    }
+}
+
+#[derive(Debug)]
+struct Day4(Vec<Pair>);
+
+impl Puzzle for Day4 {
+    fn from_string(s: String) -> Result<Self> {
+        let pairs = utils::parse_lines(&s, str::parse::<Pair>)?;
+
+        Ok(Self(pairs))
+    }
+
+    fn solve1(&self) -> Result<usize> {
+        Ok(self.0.iter().filter(|pair| pair.has_full_overlap()).count())
+    }
+
+    fn solve2(&self) -> Result<usize> {
+        Ok(self.0.iter().filter(|pair| pair.has_overlap()).count())
+    }
+}
+
+utils::solution!(2022, 4, Day4);
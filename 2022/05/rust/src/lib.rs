@@ -1,5 +1,8 @@
 mod parser;
-use utils::Puzzle;
+use {
+    nom::Finish,
+    utils::{Error, Puzzle, Result},
+};
 
 #[cfg(test)]
 mod tests;
@@ -54,10 +57,18 @@ struct Day5 {
 }
 
 impl Day5 {
-    fn rearrange(&mut self, step: Step, crane: Crane) {
-        let from = self.cargo.get_mut(step.from).unwrap();
+    fn rearrange(&mut self, step: Step, crane: Crane) -> Result<()> {
+        let from = self
+            .cargo
+            .get_mut(step.from)
+            .filter(|from| from.len() >= step.n)
+            .ok_or_else(|| Error::no_solution(format!("cannot lift {:?}", step)))?;
         let iter = crane.lift(step.n, from);
-        self.cargo.get_mut(step.to).unwrap().extend(iter);
+        self.cargo
+            .get_mut(step.to)
+            .ok_or_else(|| Error::no_solution(format!("cannot drop {:?}", step)))?
+            .extend(iter);
+        Ok(())
     }
 
     fn first(&self) -> String {
@@ -69,35 +80,28 @@ impl Day5 {
 }
 
 impl Puzzle<String, String> for Day5 {
-    fn from_string(s: String) -> Self {
-        parser::parse_day5(&s).unwrap().1
+    fn from_string(s: String) -> Result<Self> {
+        parser::parse_day5(&s)
+            .finish()
+            .map(|(_, day)| day)
+            .map_err(|err| Error::parse_rest(&s, err.input, err.code.description()))
     }
 
-    fn solve1(&self) -> String {
+    fn solve1(&self) -> Result<String> {
         let mut ship = self.clone();
         while let Some(step) = ship.steps.pop() {
-            ship.rearrange(step, Crane::Mover9000)
+            ship.rearrange(step, Crane::Mover9000)?
         }
-        ship.first()
+        Ok(ship.first())
     }
 
-    fn solve2(&self) -> String {
+    fn solve2(&self) -> Result<String> {
         let mut ship = self.clone();
         while let Some(step) = ship.steps.pop() {
-            ship.rearrange(step, Crane::Mover9001)
+            ship.rearrange(step, Crane::Mover9001)?
         }
-        ship.first()
+        Ok(ship.first())
     }
 }
 
-fn main() {
-    let puzzle = Day5::from_file();
-
-    let part1 = puzzle.solve1();
-    println!("Part 1: answer is {}.", part1);
-    assert_eq!(part1, "CVCWCRTVQ");
-
-    let part2 = puzzle.solve2();
-    println!("Part 2: answer is {}.", part2);
-    assert_eq!(part2, "CNSCZWLVT");
-}
+utils::solution!(2022, 5, Day5);
@@ -4,16 +4,16 @@ use {
         branch::alt,
         bytes::complete::tag,
         character::complete::{alpha1, char, digit1, line_ending, space0, space1},
-        combinator::{all_consuming, map, map_res, opt},
+        combinator::{all_consuming, map, map_opt, map_res, opt},
         multi::{many1, many_m_n},
         sequence::{delimited, pair, terminated, tuple},
         IResult,
     },
 };
 
-type ParseResult<'a, T> = IResult<&'a str, T, ()>;
+type ParseResult<'a, T> = IResult<&'a str, T>;
 
-fn parse_crate(input: &str) -> ParseResult<Crate> {
+fn parse_crate(input: &str) -> ParseResult<'_, Crate> {
     delimited(char('['), map(alpha1, Crate::new), char(']'))(input)
 }
 
@@ -23,7 +23,7 @@ enum Slot {
     Full(Crate),
 }
 
-fn parse_row(input: &str) -> ParseResult<Vec<Slot>> {
+fn parse_row(input: &str) -> ParseResult<'_, Vec<Slot>> {
     many1(terminated(
         alt((
             map(parse_crate, Slot::Full),
@@ -34,14 +34,14 @@ fn parse_row(input: &str) -> ParseResult<Vec<Slot>> {
 }
 
 fn transpose<T>(mat: Vec<Vec<T>>) -> Vec<Vec<T>> {
-    let len = mat.get(0).unwrap().len();
+    let len = mat.first().unwrap().len();
     let mut iters: Vec<_> = mat.into_iter().map(|n| n.into_iter()).collect();
     (0..len)
         .map(|_| -> Vec<T> { iters.iter_mut().map(|n| n.next().unwrap()).collect() })
         .collect()
 }
 
-fn parse_cargo(input: &str) -> ParseResult<Vec<Vec<Crate>>> {
+fn parse_cargo(input: &str) -> ParseResult<'_, Vec<Vec<Crate>>> {
     map(
         many1(terminated(parse_row, line_ending)),
         |mat| -> Vec<Vec<Crate>> {
@@ -61,19 +61,19 @@ fn parse_cargo(input: &str) -> ParseResult<Vec<Vec<Crate>>> {
     )(input)
 }
 
-fn step_tag<'a>(t: &'static str) -> impl FnMut(&'a str) -> ParseResult<usize> {
+fn step_tag<'a>(t: &'static str) -> impl FnMut(&'a str) -> ParseResult<'_, usize> {
     map_res(
         delimited(pair(tag(t), space1), digit1, space0),
         |val: &str| val.parse::<usize>(),
     )
 }
 
-fn parse_steps(input: &str) -> ParseResult<Vec<Step>> {
+fn parse_steps(input: &str) -> ParseResult<'_, Vec<Step>> {
     map(
         many1(terminated(
-            map(
+            map_opt(
                 tuple((step_tag("move"), step_tag("from"), step_tag("to"))),
-                |(n, from, to)| Step::new(n, from, to),
+                |(n, from, to)| (from > 0 && to > 0).then(|| Step::new(n, from, to)),
             ),
             line_ending,
         )),
@@ -84,7 +84,7 @@ fn parse_steps(input: &str) -> ParseResult<Vec<Step>> {
     )(input)
 }
 
-pub(crate) fn parse_day5(input: &str) -> ParseResult<Day5> {
+pub(crate) fn parse_day5(input: &str) -> ParseResult<'_, Day5> {
     all_consuming(map(
         tuple((
             delimited(
@@ -1,21 +1,11 @@
 use super::*;
 
-const SAMPLE: &str = r#"
-    [D]    
-[N] [C]    
-[Z] [M] [P]
- 1   2   3 
-
-move 1 from 2 to 1
-move 3 from 1 to 3
-move 2 from 2 to 1
-move 1 from 1 to 2
-"#;
+const SAMPLE: &str = include_str!("../../samples/1.txt");
 
 #[test]
 fn test_parser() {
     assert_eq!(
-        Day5::from_string(SAMPLE.into()),
+        Day5::from_string(SAMPLE.into()).unwrap(),
         Day5 {
             cargo: vec![
                 vec![Crate::new("Z"), Crate::new("N")],
@@ -31,13 +21,3 @@ fn test_parser() {
         }
     );
 }
-
-#[test]
-fn test_part1() {
-    assert_eq!(Day5::from_string(SAMPLE.into()).solve1(), "CMZ")
-}
-
-#[test]
-fn test_part2() {
-    assert_eq!(Day5::from_string(SAMPLE.into()).solve2(), "MCD")
-}
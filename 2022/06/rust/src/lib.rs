@@ -1,7 +1,7 @@
-use {std::collections::HashSet, utils::Puzzle};
-
-#[cfg(test)]
-mod tests;
+use {
+    std::collections::HashSet,
+    utils::{Error, Puzzle, Result},
+};
 
 #[derive(Debug, Clone)]
 struct Day6 {
@@ -15,7 +15,7 @@ enum Kind {
 }
 
 impl Day6 {
-    fn detect(&self, kind: Kind) -> usize {
+    fn detect(&self, kind: Kind) -> Result<usize> {
         let size = kind as usize;
         self.stream
             .windows(size)
@@ -23,33 +23,23 @@ impl Day6 {
             .find_map(|(i, window)| {
                 (window.iter().collect::<HashSet<&char>>().len() == size).then_some(i + size)
             })
-            .unwrap()
+            .ok_or_else(|| Error::no_solution(format!("no marker of {} distinct characters", size)))
     }
 }
 
 impl Puzzle for Day6 {
-    fn from_string(s: String) -> Self {
+    fn from_string(s: String) -> Result<Self> {
         let stream = s.chars().filter(|c| c.is_alphanumeric()).collect();
-        Self { stream }
+        Ok(Self { stream })
     }
 
-    fn solve1(&self) -> usize {
+    fn solve1(&self) -> Result<usize> {
         self.detect(Kind::Packet)
     }
 
-    fn solve2(&self) -> usize {
+    fn solve2(&self) -> Result<usize> {
         self.detect(Kind::Message)
     }
 }
 
-fn main() {
-    let puzzle = Day6::from_file();
-
-    let part1 = puzzle.solve1();
-    println!("Part 1: answer is {}.", part1);
-    assert_eq!(part1, 1876);
-
-    let part2 = puzzle.solve2();
-    println!("Part 2: answer is {}.", part2);
-    assert_eq!(part2, 2202);
-}
+utils::solution!(2022, 6, Day6);
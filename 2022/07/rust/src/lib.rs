@@ -1,10 +1,11 @@
-use {std::collections::HashMap, std::ops::AddAssign, utils::Puzzle};
+use {
+    std::collections::HashMap,
+    std::ops::AddAssign,
+    utils::{Error, Puzzle, Result},
+};
 
 mod parser;
 
-#[cfg(test)]
-mod tests;
-
 // Capacity of the filesystem
 const DISK_SPACE: usize = 70_000_000;
 
@@ -55,11 +56,9 @@ impl Path {
     }
 }
 
-impl ToString for Path {
-    fn to_string(&self) -> String {
-        self.0
-            .iter()
-            .fold(String::new(), |acc, dir| acc + &dir.name)
+impl std::fmt::Display for Path {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.iter().try_for_each(|dir| write!(f, "{}", dir.name))
     }
 }
 
@@ -125,11 +124,9 @@ impl FileSystem {
     fn occupied(&self, limit: Option<usize>) -> usize {
         let dir_space = self.dirs.values();
         if let Some(limit) = limit {
-            dir_space
-                .filter_map(|size| (size <= &limit).then_some(size))
-                .sum()
+            dir_space.filter(|size| *size <= &limit).sum()
         } else {
-            *dir_space.max().unwrap()
+            dir_space.max().copied().unwrap_or_default()
         }
     }
 }
@@ -149,35 +146,30 @@ impl From<Day7> for FileSystem {
 }
 
 impl Puzzle for Day7 {
-    fn from_string(s: String) -> Self {
-        s.parse::<Self>().unwrap()
+    fn from_string(s: String) -> Result<Self> {
+        s.parse::<Self>()
     }
 
-    fn solve1(&self) -> usize {
-        FileSystem::from(self.clone()).occupied(Some(100_000))
+    fn solve1(&self) -> Result<usize> {
+        Ok(FileSystem::from(self.clone()).occupied(Some(100_000)))
     }
 
-    fn solve2(&self) -> usize {
+    fn solve2(&self) -> Result<usize> {
         let fs: FileSystem = self.clone().into();
         let occupied = fs.occupied(None);
-        let vacant = DISK_SPACE.checked_sub(occupied).unwrap();
-        let needed = NEED_SPACE.checked_sub(vacant).unwrap();
-        *fs.dirs
+        let vacant = DISK_SPACE
+            .checked_sub(occupied)
+            .ok_or_else(|| Error::no_solution("files exceed the disk space"))?;
+        let needed = NEED_SPACE
+            .checked_sub(vacant)
+            .ok_or_else(|| Error::no_solution("enough space is already available"))?;
+        fs.dirs
             .values()
             .filter(|size| size > &&needed)
             .reduce(|small, size| if size <= small { size } else { small })
-            .unwrap()
+            .copied()
+            .ok_or_else(|| Error::no_solution("no directory is large enough"))
     }
 }
 
-fn main() {
-    let puzzle = Day7::from_file();
-
-    let part1 = puzzle.solve1();
-    println!("Part 1: answer is {}.", part1);
-    assert_eq!(part1, 1_086_293);
-
-    let part2 = puzzle.solve2();
-    println!("Part 2: answer is {}.", part2);
-    assert_eq!(part2, 366_028);
-}
+utils::solution!(2022, 7, Day7);
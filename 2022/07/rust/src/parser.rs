@@ -9,15 +9,16 @@ use {
         sequence::{delimited, pair, preceded, separated_pair},
         Finish, IResult,
     },
+    utils::Error,
 };
 
-type ParseResult<'a, T> = IResult<&'a str, T, ()>;
+type ParseResult<'a, T> = IResult<&'a str, T>;
 
-fn parse_dir_name(input: &str) -> ParseResult<Dir> {
+fn parse_dir_name(input: &str) -> ParseResult<'_, Dir> {
     map(alpha1, |name: &str| Dir { name: name.into() })(input)
 }
 
-fn parse_cmd_cd(input: &str) -> ParseResult<Command> {
+fn parse_cmd_cd(input: &str) -> ParseResult<'_, Command> {
     delimited(
         pair(tag("cd"), space1),
         alt((
@@ -29,17 +30,17 @@ fn parse_cmd_cd(input: &str) -> ParseResult<Command> {
     )(input)
 }
 
-fn parse_file_name(input: &str) -> ParseResult<&str> {
+fn parse_file_name(input: &str) -> ParseResult<'_, &str> {
     take_while1(|c: char| c.is_alphabetic() || c == '.')(input)
 }
 
-fn parse_file_size(input: &str) -> ParseResult<usize> {
+fn parse_file_size(input: &str) -> ParseResult<'_, usize> {
     map_res(nom::character::complete::digit1, |d: &str| {
         d.parse::<usize>()
     })(input)
 }
 
-fn parse_file(input: &str) -> ParseResult<Entry> {
+fn parse_file(input: &str) -> ParseResult<'_, Entry> {
     map(
         separated_pair(parse_file_size, char(' '), parse_file_name),
         |(size, name)| {
@@ -51,14 +52,14 @@ fn parse_file(input: &str) -> ParseResult<Entry> {
     )(input)
 }
 
-fn parse_dir(input: &str) -> ParseResult<Entry> {
+fn parse_dir(input: &str) -> ParseResult<'_, Entry> {
     map(
         preceded(pair(tag("dir"), space0), parse_dir_name),
         Entry::Dir,
     )(input)
 }
 
-fn parse_cmd_ls(input: &str) -> ParseResult<Command> {
+fn parse_cmd_ls(input: &str) -> ParseResult<'_, Command> {
     map(
         preceded(
             pair(tag("ls"), newline),
@@ -68,7 +69,7 @@ fn parse_cmd_ls(input: &str) -> ParseResult<Command> {
     )(input)
 }
 
-fn parse_cmd(input: &str) -> ParseResult<Command> {
+fn parse_cmd(input: &str) -> ParseResult<'_, Command> {
     delimited(
         pair(tag("$"), space1),
         alt((parse_cmd_cd, parse_cmd_ls)),
@@ -77,10 +78,12 @@ fn parse_cmd(input: &str) -> ParseResult<Command> {
 }
 
 impl std::str::FromStr for Day7 {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (_, cmd) = all_consuming(preceded(opt(newline), many1(parse_cmd)))(s).finish()?;
+        let (_, cmd) = all_consuming(preceded(opt(newline), many1(parse_cmd)))(s)
+            .finish()
+            .map_err(|err| Error::parse_rest(s, err.input, err.code.description()))?;
         Ok(Self { cmd })
     }
 }
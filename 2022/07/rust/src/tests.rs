@@ -1,33 +0,0 @@
-use super::*;
-
-const SAMPLE: &str = r#"
-$ cd /
-$ ls
-dir a
-14848514 b.txt
-8504156 c.dat
-dir d
-$ cd a
-$ ls
-dir e
-29116 f
-2557 g
-62596 h.lst
-$ cd e
-$ ls
-584 i
-$ cd ..
-$ cd ..
-$ cd d
-$ ls
-4060174 j
-8033020 d.log
-5626152 d.ext
-7214296 k
-"#;
-
-#[test]
-fn test_parser() {
-    assert_eq!(SAMPLE.parse::<Day7>().unwrap().solve1(), 95_437);
-    assert_eq!(SAMPLE.parse::<Day7>().unwrap().solve2(), 24_933_642);
-}
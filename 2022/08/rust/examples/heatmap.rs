@@ -0,0 +1,15 @@
+use {
+    day_2022_08::{heatmap, SOLUTION},
+    std::io::BufWriter,
+    utils::{Input, Result},
+};
+
+// Writes the heatmap of the day's input next to this crate's manifest
+fn main() -> Result<()> {
+    let input = Input::locate(&SOLUTION).read()?;
+    let path = std::path::Path::new(SOLUTION.manifest_dir).join("heatmap.png");
+    let file = std::fs::File::create(&path)?;
+    heatmap(&input)?.write_png(BufWriter::new(file))?;
+    println!("{}", path.display());
+    Ok(())
+}
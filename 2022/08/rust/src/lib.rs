@@ -0,0 +1,120 @@
+use utils::{Direction, Error, Grid, Image, Point, Puzzle, Result, Rgb};
+
+#[cfg(test)]
+mod tests;
+
+// Where an elf can look from a tree, clockwise
+const VIEWS: [Direction; 4] = [
+    Direction::North,
+    Direction::East,
+    Direction::South,
+    Direction::West,
+];
+
+#[derive(PartialEq, Eq, Clone, Debug)]
+struct Day8(Grid<usize>);
+
+impl Day8 {
+    fn height(&self, tree: Point) -> usize {
+        self.0.get(tree).copied().unwrap_or_default()
+    }
+
+    // Heights of the trees in sight, from the closest one to the edge
+    fn trees(&self, tree: Point, view: Direction) -> impl Iterator<Item = usize> + '_ {
+        self.0.ray(tree, view).map(|(_, height)| *height)
+    }
+
+    // Trees on the edge are visible since nothing stands in their way
+    fn is_visible(&self, tree: Point) -> bool {
+        let height = self.height(tree);
+        VIEWS
+            .into_iter()
+            .any(|view| self.trees(tree, view).all(|other| other < height))
+    }
+
+    fn count_visible(&self) -> usize {
+        self.0
+            .points()
+            .into_iter()
+            .filter(|tree| self.is_visible(*tree))
+            .count()
+    }
+
+    // Trees seen until the first one at least as high, included
+    fn viewing_distance(&self, tree: Point) -> [usize; 4] {
+        let height = self.height(tree);
+        VIEWS.map(|view| {
+            let mut trees_at_sight = 0;
+            for other in self.trees(tree, view) {
+                trees_at_sight += 1;
+                if other >= height {
+                    break;
+                }
+            }
+            trees_at_sight
+        })
+    }
+
+    fn scenic_score(&self, tree: Point) -> usize {
+        self.viewing_distance(tree).iter().product()
+    }
+
+    fn best_scenic_score(&self) -> usize {
+        self.0
+            .points()
+            .into_iter()
+            .map(|tree| self.scenic_score(tree))
+            .max()
+            .unwrap_or_default()
+    }
+}
+
+impl std::str::FromStr for Day8 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, |height| {
+            height
+                .to_digit(10)
+                .map(|height| height as usize)
+                .ok_or_else(|| format!("invalid height {:?}", height))
+        })
+        .map(Self)
+    }
+}
+
+impl std::fmt::Display for Day8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let heights = self
+            .0
+            .display(|height| char::from_digit(*height as u32, 10).unwrap_or('?'));
+        write!(f, "{}", heights)
+    }
+}
+
+impl Puzzle for Day8 {
+    fn from_string(s: String) -> Result<Self> {
+        s.parse::<Self>()
+    }
+
+    fn solve1(&self) -> Result<usize> {
+        Ok(self.count_visible())
+    }
+
+    fn solve2(&self) -> Result<usize> {
+        Ok(self.best_scenic_score())
+    }
+}
+
+// The scenic score of every tree, from dark for the lowest to light for the
+// best. Write it with `cargo run -p day-2022-08 --example heatmap`.
+pub fn heatmap(input: &str) -> Result<Image> {
+    let day = input.parse::<Day8>()?;
+    let scores = Grid::from_fn(day.0.cols(), day.0.rows(), |tree| day.scenic_score(tree));
+    let best = day.best_scenic_score() as f64;
+    Ok(scores
+        .image(|score| Rgb::heat(*score as f64, 0., best))
+        .scale(4))
+}
+
+utils::solution!(2022, 8, Day8);
@@ -1,34 +1,33 @@
 use super::*;
 
-const SAMPLE: &str = r#"
-30373
-25512
-65332
-33549
-35390
-"#;
+const SAMPLE: &str = include_str!("../../samples/1.txt");
 
 #[test]
 fn test_grid() {
     let grid = SAMPLE.parse::<Day8>().unwrap();
     println!("{}", grid);
 
-    let mat: Day8 = vec![
-        vec![3, 0, 3, 7, 3],
-        vec![2, 5, 5, 1, 2],
-        vec![6, 5, 3, 3, 2],
-        vec![3, 3, 5, 4, 9],
-        vec![3, 5, 3, 9, 0],
-    ]
-    .into();
+    let mat = Day8(
+        vec![
+            vec![3, 0, 3, 7, 3],
+            vec![2, 5, 5, 1, 2],
+            vec![6, 5, 3, 3, 2],
+            vec![3, 3, 5, 4, 9],
+            vec![3, 5, 3, 9, 0],
+        ]
+        .into(),
+    );
     assert_eq!(grid, mat);
 
-    assert_eq!(grid.row(0), vec![3, 0, 3, 7, 3]);
-    assert_eq!(grid.col(0), vec![3, 2, 6, 3, 3]);
-    assert_eq!(grid.get(2, 2), 3);
+    assert_eq!(grid.0.get_row(0), Some(&[3, 0, 3, 7, 3][..]));
+    assert_eq!(
+        grid.0.get_col(0).unwrap().copied().collect::<Vec<_>>(),
+        vec![3, 2, 6, 3, 3]
+    );
+    assert_eq!(grid.height(Point(2, 2)), 3);
 
-    assert_eq!(grid.rows(), 5);
-    assert_eq!(grid.cols(), 5);
+    assert_eq!(grid.0.rows(), 5);
+    assert_eq!(grid.0.cols(), 5);
 }
 
 #[test]
@@ -36,40 +35,40 @@ fn test_part1() {
     let grid = SAMPLE.parse::<Day8>().unwrap();
 
     // outer
-    assert_eq!(grid.is_visible(0, 1), true);
-    assert_eq!(grid.is_visible(5, 1), true);
-    assert_eq!(grid.is_visible(1, 5), true);
-    assert_eq!(grid.is_visible(1, 0), true);
+    assert!(grid.is_visible(Point(1, 0)));
+    assert!(grid.is_visible(Point(1, 4)));
+    assert!(grid.is_visible(Point(4, 1)));
+    assert!(grid.is_visible(Point(0, 1)));
 
     // top
-    assert_eq!(grid.get(1, 1), 5);
-    assert_eq!(grid.is_visible(1, 1), true);
+    assert_eq!(grid.height(Point(1, 1)), 5);
+    assert!(grid.is_visible(Point(1, 1)));
 
-    assert_eq!(grid.get(1, 2), 5);
-    assert_eq!(grid.is_visible(1, 2), true);
+    assert_eq!(grid.height(Point(2, 1)), 5);
+    assert!(grid.is_visible(Point(2, 1)));
 
-    assert_eq!(grid.get(1, 3), 1);
-    assert_eq!(grid.is_visible(1, 3), false);
+    assert_eq!(grid.height(Point(3, 1)), 1);
+    assert!(!grid.is_visible(Point(3, 1)));
 
     // mid
-    assert_eq!(grid.get(2, 1), 5);
-    assert_eq!(grid.is_visible(2, 1), true);
+    assert_eq!(grid.height(Point(1, 2)), 5);
+    assert!(grid.is_visible(Point(1, 2)));
 
-    assert_eq!(grid.get(2, 2), 3);
-    assert_eq!(grid.is_visible(2, 2), false);
+    assert_eq!(grid.height(Point(2, 2)), 3);
+    assert!(!grid.is_visible(Point(2, 2)));
 
-    assert_eq!(grid.get(2, 3), 3);
-    assert_eq!(grid.is_visible(2, 3), true);
+    assert_eq!(grid.height(Point(3, 2)), 3);
+    assert!(grid.is_visible(Point(3, 2)));
 
     // bottom
-    assert_eq!(grid.get(3, 1), 3);
-    assert_eq!(grid.is_visible(3, 1), false);
+    assert_eq!(grid.height(Point(1, 3)), 3);
+    assert!(!grid.is_visible(Point(1, 3)));
 
-    assert_eq!(grid.get(3, 2), 5);
-    assert_eq!(grid.is_visible(3, 2), true);
+    assert_eq!(grid.height(Point(2, 3)), 5);
+    assert!(grid.is_visible(Point(2, 3)));
 
-    assert_eq!(grid.get(3, 3), 4);
-    assert_eq!(grid.is_visible(3, 3), false);
+    assert_eq!(grid.height(Point(3, 3)), 4);
+    assert!(!grid.is_visible(Point(3, 3)));
 
     assert_eq!(grid.count_visible(), 21);
 }
@@ -77,21 +76,39 @@ fn test_part1() {
 #[test]
 fn test_part2() {
     let grid = SAMPLE.parse::<Day8>().unwrap();
-
-    let dir = Direction::Outwards;
-    assert_eq!(grid.get(1, 2), 5);
-    assert_eq!(grid.trees(1, 2, View::North, dir), vec![3]);
-    assert_eq!(grid.trees(1, 2, View::East, dir), vec![5, 2]);
-    assert_eq!(grid.trees(1, 2, View::West, dir), vec![1, 2]);
-    assert_eq!(grid.trees(1, 2, View::South, dir), vec![3, 5, 3]);
-    assert_eq!(grid.viewing_distance(1, 2), vec![1, 1, 2, 2]);
-    assert_eq!(grid.scenic_score(1, 2), 4);
-
-    assert_eq!(grid.get(3, 2), 5);
-    assert_eq!(grid.trees(3, 2, View::North, dir), vec![3, 5, 3]);
-    assert_eq!(grid.trees(3, 2, View::East, dir), vec![3, 3]);
-    assert_eq!(grid.trees(3, 2, View::West, dir), vec![4, 9]);
-    assert_eq!(grid.trees(3, 2, View::South, dir), vec![3]);
-    assert_eq!(grid.viewing_distance(3, 2), vec![2, 2, 2, 1]);
+    let trees = |tree, view| grid.trees(tree, view).collect::<Vec<_>>();
+
+    // Rows grow northwards, towards the bottom of the map
+    let tree = Point(2, 1);
+    assert_eq!(grid.height(tree), 5);
+    assert_eq!(trees(tree, Direction::South), vec![3]);
+    assert_eq!(trees(tree, Direction::West), vec![5, 2]);
+    assert_eq!(trees(tree, Direction::East), vec![1, 2]);
+    assert_eq!(trees(tree, Direction::North), vec![3, 5, 3]);
+    assert_eq!(grid.viewing_distance(tree), [2, 2, 1, 1]);
+    assert_eq!(grid.scenic_score(tree), 4);
+
+    let tree = Point(2, 3);
+    assert_eq!(grid.height(tree), 5);
+    assert_eq!(trees(tree, Direction::South), vec![3, 5, 3]);
+    assert_eq!(trees(tree, Direction::West), vec![3, 3]);
+    assert_eq!(trees(tree, Direction::East), vec![4, 9]);
+    assert_eq!(trees(tree, Direction::North), vec![3]);
+    assert_eq!(grid.viewing_distance(tree), [1, 2, 2, 2]);
+    assert_eq!(grid.scenic_score(tree), 8);
     assert_eq!(grid.best_scenic_score(), 8);
 }
+
+#[test]
+fn test_heatmap() {
+    let image = heatmap(SAMPLE).unwrap();
+    assert_eq!(image.size(), (20, 20));
+    // The best tree, third column of the fourth row, is the lightest
+    let rgb = image.rgb();
+    let pixel = |col: usize, row: usize| {
+        let i = (row * 4 * 20 + col * 4) * 3;
+        Rgb(rgb[i], rgb[i + 1], rgb[i + 2])
+    };
+    assert_eq!(pixel(2, 3), Rgb::heat(1., 0., 1.));
+    assert_eq!(pixel(0, 0), Rgb::heat(0., 0., 1.));
+}
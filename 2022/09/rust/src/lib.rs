@@ -1,4 +1,4 @@
-use utils::{Direction, Point, Puzzle, SparseGrid, Step};
+use utils::{Direction, Point, Puzzle, Result, SparseGrid, Step};
 
 #[cfg(test)]
 mod tests;
@@ -7,31 +7,32 @@ mod tests;
 struct Day9(Vec<Step>);
 
 impl std::str::FromStr for Day9 {
-    type Err = ();
+    type Err = utils::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(
-            s.lines()
-                .filter(|line| !line.is_empty())
-                .map(|line| {
-                    let (direction, steps) = line.split_once(' ').unwrap();
-                    let steps = steps.parse::<isize>().unwrap();
-                    let direction = match direction {
-                        "U" => Direction::North,
-                        "D" => Direction::South,
-                        "R" => Direction::East,
-                        "L" => Direction::West,
-                        _ => unreachable!(),
-                    };
-                    Step(direction, steps)
-                })
-                .collect(),
-        ))
+        Ok(Self(utils::parse_lines(s, |line| {
+            let (direction, steps) = line
+                .split_once(' ')
+                .ok_or_else(|| format!("expected a direction and a distance in {:?}", line))?;
+            let steps = steps.parse::<isize>().map_err(|err| err.to_string())?;
+            let direction = direction.parse::<Direction>()?;
+            Ok::<_, String>(Step(direction, steps))
+        })?))
     }
 }
 
 impl Day9 {
     fn trace(&self, knots: usize) -> SparseGrid<usize> {
+        self.trace_with(knots, |_, _| {})
+    }
+
+    // Calls `on_step` with the rope and the trace so far after each step of
+    // the head, e.g. to record the simulation
+    fn trace_with(
+        &self,
+        knots: usize,
+        mut on_step: impl FnMut(&[Point], &SparseGrid<usize>),
+    ) -> SparseGrid<usize> {
         assert!(knots > 1);
 
         let is_tail = |knot: usize| -> bool { knot == knots - 2 };
@@ -40,7 +41,7 @@ impl Day9 {
         rope.resize_with(knots, Point::default);
 
         let mut trace = SparseGrid::new();
-        trace += *rope.get(0).unwrap();
+        trace += *rope.first().unwrap();
 
         self.0.iter().for_each(|Step(direction, distance)| {
             (0..*distance).for_each(|_step| {
@@ -57,6 +58,7 @@ impl Day9 {
                         }
                     }
                 });
+                on_step(&rope, &trace);
             });
         });
         trace
@@ -64,24 +66,17 @@ impl Day9 {
 }
 
 impl Puzzle for Day9 {
-    fn solve1(&self) -> usize {
-        self.trace(2).len()
+    fn from_string(s: String) -> Result<Self> {
+        s.parse::<Self>()
     }
 
-    fn solve2(&self) -> usize {
-        // Visualize with: https://scristobal.github.io/rust-wasm-playground/
-        self.trace(10).len()
+    fn solve1(&self) -> Result<usize> {
+        Ok(self.trace(2).len())
     }
-}
-
-fn main() {
-    let puzzle = Day9::from_file();
 
-    let part1 = puzzle.solve1();
-    println!("Part 1: answer is {}.", part1);
-    assert_eq!(part1, 6044);
-
-    let part1 = puzzle.solve2();
-    println!("Part 2: answer is {}.", part1);
-    assert_eq!(part1, 2384);
+    fn solve2(&self) -> Result<usize> {
+        Ok(self.trace(10).len())
+    }
 }
+
+utils::solution!(2022, 9, Day9);
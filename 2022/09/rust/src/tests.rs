@@ -1,26 +1,8 @@
 use super::*;
 
 const SAMPLES: [&str; 2] = [
-    r#"
-R 4
-U 4
-L 3
-D 1
-R 4
-D 1
-L 5
-R 2
-"#,
-    r#"
-R 5
-U 8
-L 8
-D 3
-R 17
-D 10
-L 25
-U 20
-"#,
+    include_str!("../../samples/1.txt"),
+    include_str!("../../samples/2.txt"),
 ];
 
 #[test]
@@ -123,14 +105,55 @@ fn test_part1() {
     day.0.push(Step(Direction::East, 2));
     assert_eq!(day.trace(2).len(), 13);
 
-    assert_eq!(SAMPLES[0].parse::<Day9>().unwrap().solve1(), 13);
+    assert_eq!(SAMPLES[0].parse::<Day9>().unwrap().solve1().unwrap(), 13);
 }
 
 #[test]
-fn test_part2() {
-    let day = SAMPLES[0].parse::<Day9>().unwrap();
-    assert_eq!(day.solve2(), 1);
-
+fn test_recording() {
     let day = SAMPLES[1].parse::<Day9>().unwrap();
-    assert_eq!(day.solve2(), 36);
+    // Every point a knot goes through, for all frames to show the same ones
+    let mut covered = SparseGrid::new();
+    day.trace_with(10, |rope, _| {
+        for knot in rope {
+            covered.insert(*knot, ());
+        }
+    });
+    let (min, max) = covered.bounds().unwrap();
+
+    let mut recorder = utils::Recorder::new(std::time::Duration::from_millis(50));
+    let trace = day.trace_with(10, |rope, trace| {
+        let frame = utils::Render::new(min, max, |point| {
+            match rope.iter().position(|knot| *knot == point) {
+                Some(0) => 'H',
+                Some(knot) => char::from_digit(knot as u32, 10).unwrap(),
+                None if trace.get(&point).is_some() => '#',
+                None => '.',
+            }
+        })
+        .y_up();
+        recorder.record(frame.to_string());
+    });
+    assert_eq!(trace, day.trace(10));
+    assert_eq!(
+        recorder.len(),
+        day.0
+            .iter()
+            .map(|Step(_, distance)| *distance as usize)
+            .sum::<usize>()
+    );
+    assert!(recorder.frames()[0].contains("1H"));
+    let lines = |frame: &String| frame.lines().count();
+    assert!(recorder
+        .frames()
+        .iter()
+        .all(|frame| lines(frame) == (max.1 - min.1 + 1) as usize));
+}
+
+#[test]
+fn test_dense_trace() {
+    let trace = SAMPLES[1].parse::<Day9>().unwrap().trace(10);
+    let dense = utils::Grid::from(trace.clone());
+    assert_eq!(dense.origin(), trace.min().unwrap());
+    assert_eq!(dense.full(), 36);
+    assert_eq!(dense.into_sparse_skipping_default(), trace);
 }
@@ -1,4 +1,4 @@
-use utils::{Direction, Grid, Point, Puzzle, Step};
+use utils::{Answer, Direction, Grid, Point, Puzzle, Result, Step};
 
 #[cfg(test)]
 mod tests;
@@ -28,22 +28,19 @@ impl Instruction {
 struct Day10(Vec<Instruction>);
 
 impl std::str::FromStr for Day10 {
-    type Err = ();
+    type Err = utils::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(Self(
-            s.lines()
-                .filter(|line| !line.is_empty())
-                .map(|line| {
-                    if line.starts_with("addx") {
-                        let (_, val) = line.split_once(' ').unwrap();
-                        Instruction::Addx(val.parse::<isize>().unwrap())
-                    } else {
-                        Instruction::Noop
-                    }
-                })
-                .collect(),
-        ))
+        Ok(Self(utils::parse_lines(s, |line| {
+            match line.split_once(' ') {
+                Some(("addx", val)) => val
+                    .parse::<isize>()
+                    .map(Instruction::Addx)
+                    .map_err(|err| err.to_string()),
+                None if line == "noop" => Ok(Instruction::Noop),
+                _ => Err(format!("unknown instruction {:?}", line)),
+            }
+        })?))
     }
 }
 
@@ -55,28 +52,34 @@ impl Day10 {
             .flat_map(|ins| ins.todo())
             .collect()
     }
+
+    // Calls `on_cycle` with the screen and the sprite after each cycle, e.g.
+    // to record the drawing
+    fn draw_screen(&self, mut on_cycle: impl FnMut(&CathodeRayTube, &Sprite)) -> CathodeRayTube {
+        let mut cycle = 0;
+        let mut register = 1;
+        let mut crt = CathodeRayTube::default();
+        let mut sprite = Sprite::default();
+        self.todo().into_iter().for_each(|todo| {
+            crt.draw(cycle, &sprite);
+            cycle += 1;
+            if let Some(addx) = todo {
+                register += addx;
+            }
+            sprite.align(register, cycle);
+            on_cycle(&crt, &sprite);
+        });
+        crt
+    }
 }
 
-#[derive(PartialEq, Copy, Clone, Default)]
+#[derive(PartialEq, Copy, Clone, Debug, Default)]
 enum Pixel {
     #[default]
     Dark,
     Lit,
 }
 
-impl std::fmt::Debug for Pixel {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Lit => '#',
-                Self::Dark => '.',
-            }
-        )
-    }
-}
-
 #[derive(Debug, Clone)]
 struct Sprite([Point; 3]);
 
@@ -109,13 +112,14 @@ impl Default for CathodeRayTube {
 }
 
 impl CathodeRayTube {
+    // The screen with the sprite drawn over it, as a frame of a recording
     #[allow(dead_code)]
-    fn show_sprite(&self, sprite: &Sprite) {
+    fn with_sprite(&self, sprite: &Sprite) -> Self {
         let mut crt = self.clone();
-        crt.0.insert(sprite.0[0], Pixel::Lit);
-        crt.0.insert(sprite.0[1], Pixel::Lit);
-        crt.0.insert(sprite.0[2], Pixel::Lit);
-        println!("Sprite position: {}\n{}", sprite.0[1].0, crt);
+        for point in sprite.0 {
+            crt.0.insert(point, Pixel::Lit);
+        }
+        crt
     }
 
     fn draw(&mut self, cycle: usize, sprite: &Sprite) {
@@ -128,26 +132,22 @@ impl CathodeRayTube {
 
 impl std::fmt::Display for CathodeRayTube {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            self.0
-                .chunks_exact(COLS)
-                .into_iter()
-                .fold(String::new(), |acc, row| {
-                    acc + &format!(
-                        "{}\n",
-                        row.iter()
-                            .fold(String::new(), |acc, col| acc + &format!("{:?}", col))
-                    )
-                })
-        )
+        let screen = self.0.display(|pixel| match pixel {
+            Pixel::Lit => '#',
+            Pixel::Dark => '.',
+        });
+        write!(f, "{}", screen)
     }
 }
 
-impl Puzzle<isize, String> for Day10 {
-    fn solve1(&self) -> isize {
-        self.todo()
+impl Puzzle<isize, Answer> for Day10 {
+    fn from_string(s: String) -> Result<Self> {
+        s.parse::<Self>()
+    }
+
+    fn solve1(&self) -> Result<isize> {
+        Ok(self
+            .todo()
             .into_iter()
             .try_fold(
                 // as fold_while
@@ -169,44 +169,16 @@ impl Puzzle<isize, String> for Day10 {
             .unwrap_or_else(|v| v)
             .2
             .iter()
-            .sum()
+            .sum())
     }
 
-    fn solve2(&self) -> String {
-        let mut cycle = 0;
-        let mut register = 1;
-        let mut crt = CathodeRayTube::default();
-        let mut sprite = Sprite::default();
-        self.todo().into_iter().for_each(|todo| {
-            crt.draw(cycle, &sprite);
-            cycle += 1;
-            if let Some(addx) = todo {
-                register += addx;
-            }
-            sprite.align(register, cycle);
-        });
-        format!("\n{}", crt)
+    // The letters drawn on the screen, or the picture itself when they can't
+    // be read, e.g. for the sample's stripes
+    fn solve2(&self) -> Result<Answer> {
+        let crt = self.draw_screen(|_, _| {});
+        Ok(utils::ocr_grid(&crt.0, |pixel| *pixel == Pixel::Lit)
+            .map_or_else(|_| Answer::Art(crt.to_string()), Answer::Text))
     }
 }
 
-fn main() {
-    let puzzle = Day10::from_file();
-
-    let part1 = puzzle.solve1();
-    println!("Part 1: answer is {}.", part1);
-    assert_eq!(part1, 12_520);
-
-    let part2 = puzzle.solve2();
-    println!("Part 2: answer is {}.", part2);
-    assert_eq!(
-        part2,
-        r#"
-####.#..#.###..####.###....##..##..#....
-#....#..#.#..#....#.#..#....#.#..#.#....
-###..####.#..#...#..#..#....#.#....#....
-#....#..#.###...#...###.....#.#.##.#....
-#....#..#.#....#....#....#..#.#..#.#....
-####.#..#.#....####.#.....##...###.####.
-"#
-    ); // EHPZPJGL
-}
+utils::solution!(2022, 10, Day10);
@@ -1,158 +1,8 @@
 use super::*;
 
 const SAMPLES: [&str; 2] = [
-    r#"
-noop
-addx 3
-addx -5"#,
-    r#"
-addx 15
-addx -11
-addx 6
-addx -3
-addx 5
-addx -1
-addx -8
-addx 13
-addx 4
-noop
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx 5
-addx -1
-addx -35
-addx 1
-addx 24
-addx -19
-addx 1
-addx 16
-addx -11
-noop
-noop
-addx 21
-addx -15
-noop
-noop
-addx -3
-addx 9
-addx 1
-addx -3
-addx 8
-addx 1
-addx 5
-noop
-noop
-noop
-noop
-noop
-addx -36
-noop
-addx 1
-addx 7
-noop
-noop
-noop
-addx 2
-addx 6
-noop
-noop
-noop
-noop
-noop
-addx 1
-noop
-noop
-addx 7
-addx 1
-noop
-addx -13
-addx 13
-addx 7
-noop
-addx 1
-addx -33
-noop
-noop
-noop
-addx 2
-noop
-noop
-noop
-addx 8
-noop
-addx -1
-addx 2
-addx 1
-noop
-addx 17
-addx -9
-addx 1
-addx 1
-addx -3
-addx 11
-noop
-noop
-addx 1
-noop
-addx 1
-noop
-noop
-addx -13
-addx -19
-addx 1
-addx 3
-addx 26
-addx -30
-addx 12
-addx -1
-addx 3
-addx 1
-noop
-noop
-noop
-addx -9
-addx 18
-addx 1
-addx 2
-noop
-noop
-addx 9
-noop
-noop
-noop
-addx -1
-addx 2
-addx -37
-addx 1
-addx 3
-noop
-addx 15
-addx -21
-addx 22
-addx -6
-addx 1
-noop
-addx 2
-addx 1
-noop
-addx -10
-noop
-noop
-addx 20
-addx 1
-addx 2
-addx 2
-addx -6
-addx -11
-noop
-noop
-noop
-"#,
+    include_str!("../../samples/1.txt"),
+    include_str!("../../samples/2.txt"),
 ];
 
 #[test]
@@ -176,24 +26,20 @@ fn test_todo() {
 }
 
 #[test]
-fn test_part1() {
-    assert_eq!(SAMPLES[0].parse::<Day10>().unwrap().solve1(), 0);
+fn test_recording() {
+    let day = SAMPLES[1].parse::<Day10>().unwrap();
+    let mut recorder = utils::Recorder::new(std::time::Duration::from_millis(20));
+    let crt = day.draw_screen(|crt, sprite| {
+        recorder.record(crt.with_sprite(sprite).0.image(|pixel| match pixel {
+            Pixel::Lit => utils::Rgb::GREEN,
+            Pixel::Dark => utils::Rgb::BLACK,
+        }));
+    });
+    assert_eq!(crt, day.draw_screen(|_, _| {}));
+    assert_eq!(recorder.len(), day.todo().len());
+    assert_eq!(recorder.frames()[0].size(), (COLS, ROWS));
 
-    //vec![420, 1_140, 1_800, 2_940, 2_880, 3_960]
-    assert_eq!(SAMPLES[1].parse::<Day10>().unwrap().solve1(), 13_140);
-}
-
-#[test]
-fn test_part2() {
-    assert_eq!(
-        SAMPLES[1].parse::<Day10>().unwrap().solve2(),
-        r#"
-##..##..##..##..##..##..##..##..##..##..
-###...###...###...###...###...###...###.
-####....####....####....####....####....
-#####.....#####.....#####.....#####.....
-######......######......######......####
-#######.......#######.......#######.....
-"#
-    );
+    let mut gif = Vec::new();
+    recorder.write_gif(utils::Rgb::BLACK, &mut gif).unwrap();
+    assert!(gif.starts_with(b"GIF89a"));
 }
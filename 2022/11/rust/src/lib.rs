@@ -1,6 +1,7 @@
 use {
+    nom::{combinator::all_consuming, multi::many1, Finish},
     std::collections::{BTreeMap, VecDeque},
-    utils::Puzzle,
+    utils::{Error, Puzzle, Result},
 };
 
 mod parser;
@@ -38,11 +39,12 @@ struct Day11 {
 }
 
 impl std::str::FromStr for Day11 {
-    type Err = ();
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let monkeys = nom::multi::many1(parser::parse_monkey)(s)
-            .unwrap()
+        let monkeys = all_consuming(many1(parser::parse_monkey))(s)
+            .finish()
+            .map_err(|err| Error::parse_rest(s, err.input, err.code.description()))?
             .1
             .into_iter()
             .fold(BTreeMap::new(), |mut acc, (key, value)| {
@@ -54,14 +56,10 @@ impl std::str::FromStr for Day11 {
 }
 
 impl Day11 {
-    fn play(&self, rounds: usize, worry_factor: Option<usize>, verbose: bool) -> Self {
+    fn play(&self, rounds: usize, worry_factor: Option<usize>, verbose: bool) -> Result<Self> {
         let mut game = self.clone();
-        let factor = worry_factor.unwrap_or_else(|| {
-            game.monkeys
-                .iter()
-                .map(|(_, monkey)| monkey.test.0)
-                .product()
-        });
+        let factor = worry_factor
+            .unwrap_or_else(|| game.monkeys.values().map(|monkey| monkey.test.0).product());
         let players = game.monkeys.len();
         let rounds = rounds * players;
         let mut round = 0;
@@ -77,6 +75,7 @@ impl Day11 {
                 );
             }
 
+            let missing = |id: MonkeyId| Error::no_solution(format!("monkey {} is missing", id.0));
             if let Some(mut monkey) = game.monkeys.remove(&current_player) {
                 while let Some(mut worry) = monkey.worries.pop_front() {
                     monkey.business += 1;
@@ -133,7 +132,7 @@ impl Day11 {
                     }
                     game.monkeys
                         .get_mut(&next_player)
-                        .unwrap()
+                        .ok_or_else(|| missing(next_player))?
                         .worries
                         .push_back(worry);
                 }
@@ -155,16 +154,18 @@ impl Day11 {
                 }
 
                 round += 1;
+            } else {
+                return Err(missing(current_player));
             }
         }
-        game
+        Ok(game)
     }
 
     fn score(&self) -> usize {
         let mut business = self
             .monkeys
-            .iter()
-            .map(|(_, monkey)| monkey.business)
+            .values()
+            .map(|monkey| monkey.business)
             .collect::<Vec<usize>>();
         business.sort_by(|a, b| b.cmp(a));
         business.truncate(2);
@@ -173,23 +174,17 @@ impl Day11 {
 }
 
 impl Puzzle<usize, usize> for Day11 {
-    fn solve1(&self) -> usize {
-        self.play(20, Some(3), false).score()
+    fn from_string(s: String) -> Result<Self> {
+        s.parse::<Self>()
     }
 
-    fn solve2(&self) -> usize {
-        self.play(10_000, None, false).score()
+    fn solve1(&self) -> Result<usize> {
+        Ok(self.play(20, Some(3), false)?.score())
     }
-}
-
-fn main() {
-    let puzzle = Day11::from_file();
-
-    let part1 = puzzle.solve1();
-    println!("Part 1: answer is {}.", part1);
-    assert_eq!(part1, 55944);
 
-    let part2 = puzzle.solve2();
-    println!("Part 2: answer is {}.", part2);
-    assert_eq!(part2, 15_117_269_860);
+    fn solve2(&self) -> Result<usize> {
+        Ok(self.play(10_000, None, false)?.score())
+    }
 }
+
+utils::solution!(2022, 11, Day11);
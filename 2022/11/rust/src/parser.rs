@@ -1,5 +1,5 @@
 use {
-    super::{Monkey, MonkeyId, Operand, Operation, Test, WorryLevel},
+    super::{Monkey, MonkeyId, Operation, Test, WorryLevel},
     nom::{
         branch::alt,
         bytes::complete::tag,
@@ -11,20 +11,20 @@ use {
     },
 };
 
-type ParseResult<'a, T> = IResult<&'a str, T, ()>;
+type ParseResult<'a, T> = IResult<&'a str, T>;
 
-fn unsigned_digit1(s: &str) -> ParseResult<usize> {
+fn unsigned_digit1(s: &str) -> ParseResult<'_, usize> {
     map_res(digit1, str::parse)(s)
 }
 
-fn parse_worries(s: &str) -> ParseResult<Vec<WorryLevel>> {
+fn parse_worries(s: &str) -> ParseResult<'_, Vec<WorryLevel>> {
     preceded(
         pair(tag("Starting items:"), space0),
         separated_list1(pair(tag(","), space0), map(unsigned_digit1, WorryLevel)),
     )(s)
 }
 
-fn parse_operation(s: &str) -> ParseResult<Operation> {
+fn parse_operation(s: &str) -> ParseResult<'_, Operation> {
     map(
         tuple((
             delimited(
@@ -46,7 +46,7 @@ fn parse_operation(s: &str) -> ParseResult<Operation> {
     )(s)
 }
 
-fn parse_test(s: &str) -> ParseResult<Test> {
+fn parse_test(s: &str) -> ParseResult<'_, Test> {
     map(
         tuple((
             delimited(
@@ -69,7 +69,7 @@ fn parse_test(s: &str) -> ParseResult<Test> {
     )(s)
 }
 
-pub(crate) fn parse_monkey(s: &str) -> ParseResult<(MonkeyId, Monkey)> {
+pub(crate) fn parse_monkey(s: &str) -> ParseResult<'_, (MonkeyId, Monkey)> {
     let (s, id) = delimited(
         multispace0,
         delimited(
@@ -0,0 +1,15 @@
+use {
+    day_2022_12::{graph, SOLUTION},
+    std::io::Write,
+    utils::{Input, Result},
+};
+
+// Writes the graph of the day's input next to this crate's manifest
+fn main() -> Result<()> {
+    let input = Input::locate(&SOLUTION).read()?;
+    let path = std::path::Path::new(SOLUTION.manifest_dir).join("graph.dot");
+    let mut file = std::fs::File::create(&path)?;
+    file.write_all(graph(&input)?.as_bytes())?;
+    println!("{}", path.display());
+    Ok(())
+}
@@ -0,0 +1,147 @@
+use utils::{Error, Grid, Neighbourhood, Point, Puzzle, Result};
+
+#[cfg(test)]
+mod tests;
+
+#[derive(Debug, Clone, Copy, PartialEq, Hash, PartialOrd, Eq, Ord)]
+enum Square {
+    Start,
+    End,
+    Other(char),
+}
+
+impl TryFrom<char> for Square {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        if c.is_ascii_lowercase() {
+            Ok(Self::Other(c))
+        } else {
+            match c {
+                'S' => Ok(Self::Start),
+                'E' => Ok(Self::End),
+                _ => Err(format!("unknown square {:?}", c)),
+            }
+        }
+    }
+}
+
+impl Square {
+    fn eval(self) -> usize {
+        let level = match self {
+            Self::Start => 'a',
+            Self::End => 'z',
+            Self::Other(z) => z,
+        };
+        level as usize % 'a' as usize
+    }
+
+    fn can_reach(self, destination: Square) -> bool {
+        destination.eval() <= self.eval() + 1
+    }
+
+    fn is_lowest(self) -> bool {
+        self.eval() == 0
+    }
+
+    fn label(self) -> char {
+        match self {
+            Self::Start => 'S',
+            Self::End => 'E',
+            Self::Other(c) => c,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Day12(Grid<Square>);
+
+impl std::str::FromStr for Day12 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.parse()?))
+    }
+}
+
+impl Day12 {
+    fn find(&self, square: Square) -> Option<Point> {
+        self.0
+            .points()
+            .into_iter()
+            .find(|point| self.0.get(*point) == Some(&square))
+    }
+
+    fn start(&self) -> Result<Point> {
+        self.find(Square::Start)
+            .ok_or_else(|| Error::no_solution("no start square"))
+    }
+
+    fn end(&self) -> Result<Point> {
+        self.find(Square::End)
+            .ok_or_else(|| Error::no_solution("no end square"))
+    }
+
+    fn lowest(&self) -> impl Iterator<Item = Point> + '_ {
+        self.0
+            .points()
+            .into_iter()
+            .filter(|point| self.0[self.0.index(*point)].is_lowest())
+    }
+
+    fn neighbours(&self, point: Point) -> impl Iterator<Item = Point> + '_ {
+        let square = self.0[self.0.index(point)];
+        self.0
+            .neighbours(point, Neighbourhood::VonNeumann)
+            .filter(move |(_, neighbour)| square.can_reach(**neighbour))
+            .map(|(point, _)| point)
+    }
+}
+
+impl Puzzle for Day12 {
+    fn from_string(s: String) -> Result<Self> {
+        s.parse::<Self>()
+    }
+
+    fn solve1(&self) -> Result<usize> {
+        let (start, end) = (self.start()?, self.end()?);
+        utils::bfs(
+            start,
+            |point| self.neighbours(*point),
+            |point| *point == end,
+        )
+        .map(|path| path.cost)
+        .ok_or_else(|| Error::no_solution("the end cannot be reached"))
+    }
+
+    fn solve2(&self) -> Result<usize> {
+        let end = self.end()?;
+        utils::bfs_from(
+            self.lowest(),
+            |point| self.neighbours(*point),
+            |point| *point == end,
+        )
+        .map(|path| path.cost)
+        .ok_or_else(|| Error::no_solution("the end cannot be reached"))
+    }
+}
+
+// The possible moves between squares as a Graphviz graph, with the squares
+// reachable from the start in grey and the shortest path to the end in red.
+// Regenerate `graph.dot` with `cargo run -p day-2022-12 --example graph`.
+pub fn graph(input: &str) -> Result<String> {
+    let day = input.parse::<Day12>()?;
+    let (start, end) = (day.start()?, day.end()?);
+    let neighbours = |point: &Point| day.neighbours(*point);
+    let reachable = utils::bfs_distances([start], neighbours);
+    let path = utils::bfs(start, neighbours, |point| *point == end)
+        .ok_or_else(|| Error::no_solution("the end cannot be reached"))?;
+    Ok(day
+        .0
+        .dot(|square| square.label().to_string(), neighbours)
+        .visited(reachable.into_keys(), "lightgrey")
+        .path(&path.states, "red")
+        .to_string())
+}
+
+utils::solution!(2022, 12, Day12);
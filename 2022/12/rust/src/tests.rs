@@ -1,19 +1,12 @@
 use super::*;
 
-const SAMPLE: &str = r#"
-Sabqponm
-abcryxxl
-accszExk
-acctuvwj
-abdefghi
-"#;
+const SAMPLE: &str = include_str!("../../samples/1.txt");
 
 #[test]
-fn test_part1() {
-    assert_eq!(SAMPLE.parse::<Day12>().unwrap().solve1(), 31);
-}
-
-#[test]
-fn test_part2() {
-    assert_eq!(SAMPLE.parse::<Day12>().unwrap().solve2(), 29);
+fn test_graph() {
+    let dot = graph(SAMPLE).unwrap();
+    assert_eq!(dot.matches("label").count(), 40);
+    assert_eq!(dot.matches("penwidth").count(), 31);
+    assert!(dot
+        .contains("\"5,2\" [label = \"E\", pos = \"5,-2!\", style = filled, fillcolor = \"red\"]"));
 }
@@ -0,0 +1,40 @@
+use {
+    criterion::{criterion_group, criterion_main, Criterion},
+    std::time::Duration,
+    utils::{Input, Run, Timings},
+};
+
+// Every registered day gets a group with one benchmark per stage, measured by
+// the solution itself so that parsing is not counted against the parts.
+fn days(c: &mut Criterion) {
+    for solution in aoc::SOLUTIONS {
+        let input = match Input::locate(solution).read() {
+            Ok(input) => input,
+            Err(err) => {
+                eprintln!("Skipping {}: {}", solution.name(), err);
+                continue;
+            }
+        };
+        let measure = |stage: fn(&Timings) -> Duration| {
+            let input = input.clone();
+            move |iters: u64| {
+                (0..iters)
+                    .map(|_| match (solution.solve)(input.clone()) {
+                        Ok(Run { timings, .. }) => stage(&timings),
+                        Err(err) => panic!("{}: {}", solution.name(), err),
+                    })
+                    .sum()
+            }
+        };
+
+        let mut group = c.benchmark_group(format!("{}/{:02}", solution.year, solution.day));
+        group.sample_size(10);
+        group.bench_function("parse", |b| b.iter_custom(measure(|t| t.parse)));
+        group.bench_function("part1", |b| b.iter_custom(measure(|t| t.part1)));
+        group.bench_function("part2", |b| b.iter_custom(measure(|t| t.part2)));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, days);
+criterion_main!(benches);
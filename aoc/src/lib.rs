@@ -0,0 +1,11 @@
+mod registry;
+pub use registry::*;
+
+mod readme;
+pub use readme::*;
+
+mod report;
+pub use report::*;
+
+#[cfg(test)]
+mod tests;
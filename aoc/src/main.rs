@@ -0,0 +1,230 @@
+use {
+    aoc::{DayReport, Report},
+    clap::{Args, Parser, Subcommand},
+    std::{
+        collections::{btree_map::Entry, BTreeMap},
+        fs::File,
+        io::{self, BufWriter, Write},
+        path::{Path, PathBuf},
+        process::ExitCode,
+    },
+    utils::{Answers, Bench, Error, Input, Result, Solution},
+};
+
+#[derive(Parser)]
+#[command(
+    name = "aoc",
+    about = "Runs the Advent of Code solutions of this repository"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solves a single day, a whole year or, by default, the whole collection
+    /// and checks the answers against each year's `answers.toml`
+    Run {
+        #[command(flatten)]
+        selection: Selection,
+        /// Writes a JSON report of the answers, statuses and timings to this file
+        #[arg(long, value_name = "PATH")]
+        json: Option<PathBuf>,
+        /// Writes the same report as JUnit XML, a test case per part
+        #[arg(long, value_name = "PATH")]
+        junit: Option<PathBuf>,
+    },
+    /// Solves the selected days repeatedly and reports how long each stage takes
+    Bench {
+        #[command(flatten)]
+        selection: Selection,
+        /// Number of runs per day
+        #[arg(short, long, default_value_t = 10)]
+        runs: usize,
+    },
+    /// Regenerates the star counts of the top-level README and the puzzle
+    /// tables of each year's README from the registered days and answers
+    Readme {
+        /// Fails if a README is out of date instead of writing it
+        #[arg(long)]
+        check: bool,
+    },
+}
+
+#[derive(Args)]
+struct Selection {
+    year: Option<u16>,
+    #[arg(requires = "year")]
+    day: Option<u8>,
+    /// Reads the input of a single day from this file, or `-` for stdin,
+    /// instead of `$AOC_INPUT_DIR/<year>/<day>/input` or the day's default
+    #[arg(short, long, requires = "day")]
+    input: Option<Input>,
+}
+
+impl Selection {
+    // Calls `fun` on each selected day, returning whether they all succeeded
+    fn for_each(&self, mut fun: impl FnMut(&Solution, &Input) -> Result<bool>) -> ExitCode {
+        let solutions = aoc::find(self.year, self.day);
+        if solutions.is_empty() {
+            eprintln!("No solution is registered for this selection.");
+            return ExitCode::FAILURE;
+        }
+        let mut success = true;
+        for (i, solution) in solutions.into_iter().enumerate() {
+            if i > 0 {
+                println!();
+            }
+            let input = self
+                .input
+                .clone()
+                .unwrap_or_else(|| Input::locate(solution));
+            match fun(solution, &input) {
+                Ok(solved) => success &= solved,
+                Err(err) => {
+                    eprintln!("{}", err);
+                    success = false;
+                }
+            }
+        }
+        if success {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn root() -> PathBuf {
+    [env!("CARGO_MANIFEST_DIR"), ".."].iter().collect()
+}
+
+// Known answers are stored per year, e.g. `2022/answers.toml`
+fn answers_path(year: u16) -> PathBuf {
+    root().join(year.to_string()).join("answers.toml")
+}
+
+fn run(solution: &Solution, input: &Input, answers: &Answers) -> DayReport {
+    let report = DayReport::new(solution, input.read().and_then(solution.solve), answers);
+    if let Some(err) = &report.error {
+        eprintln!("{}", err);
+        return report;
+    }
+    println!(
+        "{} (parsed in {:?})",
+        report.name,
+        report.parse.unwrap_or_default()
+    );
+    for part in &report.parts {
+        let time = part.time.unwrap_or_default();
+        match (&part.answer, part.verdict()) {
+            (Some(answer), Some(status)) => {
+                if answer.is_art() {
+                    println!(
+                        "Part {}: answer ({}) in {:?}:\n{}",
+                        part.part, status, time, answer
+                    );
+                } else {
+                    println!(
+                        "Part {}: answer is {} ({}) in {:?}.",
+                        part.part, answer, status, time
+                    );
+                }
+            }
+            _ => println!(
+                "Part {}: {}",
+                part.part,
+                part.error.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+    report
+}
+
+fn write_report(path: &Path, write: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>) -> bool {
+    let written = File::create(path).and_then(|file| {
+        let mut writer = BufWriter::new(file);
+        write(&mut writer)?;
+        writer.flush()
+    });
+    match written {
+        Ok(()) => true,
+        Err(err) => {
+            eprintln!("Failed to write report {}: {}.", path.display(), err);
+            false
+        }
+    }
+}
+
+fn bench(solution: &Solution, input: &Input, runs: usize) -> Result<bool> {
+    let input = input.read()?;
+    let timings = (0..runs)
+        .map(|_| (solution.solve)(input.clone()).map(|run| run.timings))
+        .collect::<Result<Vec<_>>>()?;
+    let bench = Bench::new(&timings).ok_or_else(|| Error::no_solution("no run to measure"))?;
+    println!("{} ({} runs)", solution.name(), bench.runs);
+    println!("Parse:  {}", bench.parse);
+    println!("Part 1: {}", bench.part1);
+    println!("Part 2: {}", bench.part2);
+    Ok(true)
+}
+
+fn readme(check: bool) -> Result<bool> {
+    let mut success = true;
+    for (path, content) in aoc::generate(&root(), aoc::SOLUTIONS)? {
+        if std::fs::read_to_string(&path)? == content {
+            continue;
+        }
+        if check {
+            eprintln!("{} is out of date.", path.display());
+            success = false;
+        } else {
+            std::fs::write(&path, content)?;
+            println!("Updated {}.", path.display());
+        }
+    }
+    Ok(success)
+}
+
+fn main() -> ExitCode {
+    match Cli::parse().command {
+        Command::Run {
+            selection,
+            json,
+            junit,
+        } => {
+            let mut answers = BTreeMap::new();
+            let mut report = Report::default();
+            let mut code = selection.for_each(|solution, input| {
+                let answers = match answers.entry(solution.year) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        entry.insert(Answers::load(answers_path(solution.year))?)
+                    }
+                };
+                let day = run(solution, input, answers);
+                let success = day.success();
+                report.days.push(day);
+                Ok(success)
+            });
+            let written = json.is_none_or(|path| write_report(&path, |w| report.write_json(w)))
+                & junit.is_none_or(|path| write_report(&path, |w| report.write_junit(w)));
+            if !written {
+                code = ExitCode::FAILURE;
+            }
+            code
+        }
+        Command::Bench { selection, runs } => {
+            selection.for_each(|solution, input| bench(solution, input, runs))
+        }
+        Command::Readme { check } => match readme(check) {
+            Ok(true) => ExitCode::SUCCESS,
+            Ok(false) => ExitCode::FAILURE,
+            Err(err) => {
+                eprintln!("{}", err);
+                ExitCode::FAILURE
+            }
+        },
+    }
+}
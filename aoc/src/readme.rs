@@ -0,0 +1,209 @@
+use {
+    std::path::{Path, PathBuf},
+    utils::{Answers, Error, Input, Result, Solution},
+};
+
+// The first year of Advent of Code, from which the top-level list starts
+pub const FIRST_YEAR: u16 = 2015;
+
+const STARS_PER_YEAR: usize = 50;
+
+// A star is earned for each part of a registered day whose answer is known
+pub fn stars(solutions: &[&Solution], answers: &Answers) -> usize {
+    solutions
+        .iter()
+        .map(|solution| {
+            (1..=2)
+                .filter(|part| answers.get(solution.day, *part).is_some())
+                .count()
+        })
+        .sum()
+}
+
+fn percent(stars: usize, total: usize) -> f64 {
+    (stars as f64 * 1000. / total as f64).round() / 10.
+}
+
+// The stars of each year, from the most recent one, then their total:
+//
+// - [ ] [2022](./2022): 24⋆ (48%)
+// - [ ] [2021](./2021): 0⋆
+// ...
+// Total stars: 30⋆ (7.5%)
+pub fn star_list(years: &[(u16, usize)]) -> String {
+    let mut list = String::new();
+    for (year, stars) in years.iter().rev() {
+        let done = if *stars == STARS_PER_YEAR { 'x' } else { ' ' };
+        list += &format!("- [{}] [{}](./{}): {}⋆", done, year, year, stars);
+        if *stars > 0 {
+            list += &format!(" ({}%)", percent(*stars, STARS_PER_YEAR));
+        }
+        list += "\n";
+    }
+    let total = years.iter().map(|(_, stars)| stars).sum::<usize>();
+    list += &format!(
+        "\nTotal stars: {}⋆ ({}%)\n",
+        total,
+        percent(total, years.len() * STARS_PER_YEAR)
+    );
+    list
+}
+
+// A day as listed in its year's README
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    pub day: u8,
+    pub title: String,
+    // Link text and target of each solution, relative to the year
+    pub solutions: Vec<(String, String)>,
+    pub stars: usize,
+}
+
+impl Entry {
+    // Looks for the puzzle's title and its R solution in the day's directory
+    pub fn scan(dir: &Path, day: u8, solution: Option<&Solution>, answers: &Answers) -> Self {
+        let title = std::fs::read_to_string(dir.join("README.md"))
+            .ok()
+            .and_then(|readme| title(&readme))
+            .unwrap_or_else(|| format!("Day {}", day));
+        let mut solutions = Vec::new();
+        if let Some(r) = ["R/README.md", "R/main.R"]
+            .into_iter()
+            .find(|path| dir.join(path).is_file())
+        {
+            solutions.push(("R".to_string(), format!("./{:02}/{}", day, r)));
+        }
+        if solution.is_some() {
+            solutions.push(("rust".to_string(), format!("./{:02}/rust/src/lib.rs", day)));
+        }
+        let stars = solution.map_or(0, |solution| stars(&[solution], answers));
+        Self {
+            day,
+            title,
+            solutions,
+            stars,
+        }
+    }
+}
+
+// The first heading of a README, without the dashes of the puzzle's page
+pub fn title(readme: &str) -> Option<String> {
+    readme
+        .lines()
+        .find_map(|line| line.strip_prefix("# "))
+        .map(|title| title.trim_matches(|c: char| c == '-' || c.is_whitespace()))
+        .map(ToString::to_string)
+}
+
+pub fn puzzle_table(entries: &[Entry]) -> String {
+    let rows = entries
+        .iter()
+        .map(|entry| {
+            [
+                format!("[{}](./{:02}/README.md)", entry.title, entry.day),
+                entry
+                    .solutions
+                    .iter()
+                    .map(|(name, path)| format!("[{}]({})", name, path))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                "⋆".repeat(entry.stars),
+            ]
+        })
+        .collect::<Vec<_>>();
+    let header = ["Puzzle", "Solution", "Stars"].map(String::from);
+    let widths = (0..header.len())
+        .map(|col| {
+            std::iter::once(&header)
+                .chain(&rows)
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    let line = |cells: &[String]| {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!(" {:<width$} ", cell, width = width))
+            .fold("|".to_string(), |acc, cell| acc + &cell + "|")
+            + "\n"
+    };
+    let separator = widths
+        .iter()
+        .map(|width| format!(":{}", "-".repeat(width + 1)))
+        .fold("|".to_string(), |acc, cell| acc + &cell + "|")
+        + "\n";
+    std::iter::once(line(&header))
+        .chain(std::iter::once(separator))
+        .chain(rows.iter().map(|row| line(row)))
+        .collect()
+}
+
+// Replaces what lies between `<!-- name -->` and `<!-- /name -->`
+pub fn replace_section(text: &str, name: &str, content: &str) -> Result<String> {
+    let (open, close) = (
+        format!("<!-- {} -->\n", name),
+        format!("<!-- /{} -->", name),
+    );
+    let start = text
+        .find(&open)
+        .ok_or_else(|| Error::parse(1, 1, format!("missing `{}`", open.trim())))?
+        + open.len();
+    let end = text[start..]
+        .find(&close)
+        .ok_or_else(|| Error::parse_at(text, start, format!("missing `{}`", close)))?
+        + start;
+    Ok(format!("{}{}{}", &text[..start], content, &text[end..]))
+}
+
+// The new content of the top-level README and of each year's README
+pub fn generate(root: &Path, solutions: &[Solution]) -> Result<Vec<(PathBuf, String)>> {
+    let last_year = solutions
+        .iter()
+        .map(|solution| solution.year)
+        .max()
+        .unwrap_or(FIRST_YEAR);
+    let mut readmes = Vec::new();
+    let mut years = Vec::new();
+    for year in FIRST_YEAR..=last_year {
+        let year_dir = root.join(year.to_string());
+        let answers = Answers::load(year_dir.join("answers.toml"))?;
+        let registered = solutions
+            .iter()
+            .filter(|solution| solution.year == year)
+            .collect::<Vec<_>>();
+        years.push((year, stars(&registered, &answers)));
+
+        let path = year_dir.join("README.md");
+        if !path.is_file() {
+            continue;
+        }
+        let entries = (1..=25)
+            .filter_map(|day| {
+                let dir = year_dir.join(format!("{:02}", day));
+                let solution = registered.iter().find(|solution| solution.day == day);
+                (dir.is_dir() || solution.is_some())
+                    .then(|| Entry::scan(&dir, day, solution.copied(), &answers))
+            })
+            .collect::<Vec<_>>();
+        let readme = update(&path, "puzzles", &puzzle_table(&entries))?;
+        readmes.push((path, readme));
+    }
+    let path = root.join("README.md");
+    let readme = update(&path, "stars", &star_list(&years))?;
+    readmes.insert(0, (path, readme));
+    Ok(readmes)
+}
+
+fn update(path: &Path, section: &str, content: &str) -> Result<String> {
+    let readme = Input::Path(path.to_path_buf()).read()?;
+    replace_section(&readme, section, content).map_err(|err| match err {
+        Error::Parse {
+            line,
+            column,
+            message,
+        } => Error::parse(line, column, format!("{} in {}", message, path.display())),
+        err => err,
+    })
+}
@@ -0,0 +1,28 @@
+use utils::Solution;
+
+// Every day of the collection, in chronological order.
+pub const SOLUTIONS: &[Solution] = &[
+    day_2015_01::SOLUTION,
+    day_2015_02::SOLUTION,
+    day_2015_03::SOLUTION,
+    day_2022_01::SOLUTION,
+    day_2022_02::SOLUTION,
+    day_2022_03::SOLUTION,
+    day_2022_04::SOLUTION,
+    day_2022_05::SOLUTION,
+    day_2022_06::SOLUTION,
+    day_2022_07::SOLUTION,
+    day_2022_08::SOLUTION,
+    day_2022_09::SOLUTION,
+    day_2022_10::SOLUTION,
+    day_2022_11::SOLUTION,
+    day_2022_12::SOLUTION,
+];
+
+pub fn find(year: Option<u16>, day: Option<u8>) -> Vec<&'static Solution> {
+    SOLUTIONS
+        .iter()
+        .filter(|solution| year.is_none_or(|year| solution.year == year))
+        .filter(|solution| day.is_none_or(|day| solution.day == day))
+        .collect()
+}
@@ -0,0 +1,233 @@
+use {
+    serde::{Serialize, Serializer},
+    std::{io::Write, time::Duration},
+    utils::{Answer, Answers, Result, Run, Solution, Status},
+};
+
+// What the runner found out about the selected days, to be written as JSON
+// for dashboards or as JUnit XML for CI test viewers. Timings are serialized
+// in seconds.
+#[derive(Debug, Default, Serialize)]
+pub struct Report {
+    pub days: Vec<DayReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct DayReport {
+    pub name: String,
+    pub year: u16,
+    pub day: u8,
+    #[serde(serialize_with = "seconds")]
+    pub parse: Option<Duration>,
+    // Why the input could not be read or parsed, in which case no part is run
+    pub error: Option<String>,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PartReport {
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub expected: Option<Answer>,
+    pub status: Outcome,
+    #[serde(serialize_with = "seconds")]
+    pub time: Option<Duration>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Correct,
+    Wrong,
+    Unknown,
+    Error,
+}
+
+impl PartReport {
+    // The status of a solved part, with the expected answer when wrong
+    pub fn verdict(&self) -> Option<Status> {
+        match (self.status, &self.expected) {
+            (Outcome::Correct, _) => Some(Status::Correct),
+            (Outcome::Wrong, Some(expected)) => Some(Status::Wrong(expected.clone())),
+            (Outcome::Unknown, _) => Some(Status::Unknown),
+            (Outcome::Wrong, None) | (Outcome::Error, _) => None,
+        }
+    }
+}
+
+fn seconds<S: Serializer>(duration: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+    duration.map(|d| d.as_secs_f64()).serialize(serializer)
+}
+
+impl DayReport {
+    pub fn new(solution: &Solution, run: Result<Run>, answers: &Answers) -> Self {
+        let mut report = Self {
+            name: solution.name(),
+            year: solution.year,
+            day: solution.day,
+            parse: None,
+            error: None,
+            parts: Vec::new(),
+        };
+        let run = match run {
+            Ok(run) => run,
+            Err(err) => {
+                report.error = Some(err.to_string());
+                return report;
+            }
+        };
+        report.parse = Some(run.timings.parse);
+        let timings = [run.timings.part1, run.timings.part2];
+        for (i, (part, time)) in run.parts.into_iter().zip(timings).enumerate() {
+            let part_number = i as u8 + 1;
+            let expected = answers.get(solution.day, part_number).cloned();
+            let (answer, status, error) = match part {
+                Ok(answer) => {
+                    let status = match answers.check(solution.day, part_number, &answer) {
+                        Status::Correct => Outcome::Correct,
+                        Status::Wrong(_) => Outcome::Wrong,
+                        Status::Unknown => Outcome::Unknown,
+                    };
+                    (Some(answer), status, None)
+                }
+                Err(err) => (None, Outcome::Error, Some(err.to_string())),
+            };
+            report.parts.push(PartReport {
+                part: part_number,
+                answer,
+                expected,
+                status,
+                time: Some(time),
+                error,
+            });
+        }
+        report
+    }
+
+    // Whether every part was solved without contradicting a known answer
+    pub fn success(&self) -> bool {
+        self.error.is_none()
+            && self
+                .parts
+                .iter()
+                .all(|part| matches!(part.status, Outcome::Correct | Outcome::Unknown))
+    }
+
+    fn write_junit(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        let count = |outcome| {
+            self.parts
+                .iter()
+                .filter(|part| part.status == outcome)
+                .count()
+        };
+        let time = self.parse.unwrap_or_default()
+            + self
+                .parts
+                .iter()
+                .filter_map(|part| part.time)
+                .sum::<Duration>();
+        writeln!(
+            writer,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" errors="{}" time="{:.6}">"#,
+            self.name,
+            self.parts.len().max(1),
+            count(Outcome::Wrong),
+            count(Outcome::Error) + usize::from(self.error.is_some()),
+            time.as_secs_f64()
+        )?;
+        let classname = format!("{}.day{:02}", self.year, self.day);
+        if let Some(error) = &self.error {
+            writeln!(
+                writer,
+                r#"    <testcase classname="{}" name="parse">"#,
+                classname
+            )?;
+            writeln!(writer, r#"      <error message="{}"/>"#, escape(error))?;
+            writeln!(writer, "    </testcase>")?;
+        }
+        for part in &self.parts {
+            writeln!(
+                writer,
+                r#"    <testcase classname="{}" name="part{}" time="{:.6}">"#,
+                classname,
+                part.part,
+                part.time.unwrap_or_default().as_secs_f64()
+            )?;
+            match (&part.status, &part.expected, &part.error) {
+                (Outcome::Wrong, Some(expected), _) => writeln!(
+                    writer,
+                    r#"      <failure message="wrong answer">expected {}</failure>"#,
+                    escape(&expected.to_string())
+                )?,
+                (Outcome::Error, _, Some(error)) => {
+                    writeln!(writer, r#"      <error message="{}"/>"#, escape(error))?
+                }
+                _ => {}
+            }
+            if let Some(answer) = &part.answer {
+                writeln!(
+                    writer,
+                    "      <system-out>{}</system-out>",
+                    escape(&answer.to_string())
+                )?;
+            }
+            writeln!(writer, "    </testcase>")?;
+        }
+        writeln!(writer, "  </testsuite>")
+    }
+}
+
+impl Report {
+    pub fn write_json(&self, writer: impl Write) -> std::io::Result<()> {
+        serde_json::to_writer_pretty(writer, self)?;
+        Ok(())
+    }
+
+    // A test suite per day and a test case per part, where wrong answers are
+    // failures and unsolved parts are errors
+    pub fn write_junit(&self, mut writer: impl Write) -> std::io::Result<()> {
+        let count = |outcome| {
+            self.days
+                .iter()
+                .flat_map(|day| &day.parts)
+                .filter(|part| part.status == outcome)
+                .count()
+        };
+        let tests = self
+            .days
+            .iter()
+            .map(|day| day.parts.len().max(1))
+            .sum::<usize>();
+        let errors =
+            count(Outcome::Error) + self.days.iter().filter(|day| day.error.is_some()).count();
+        writeln!(writer, r#"<?xml version="1.0" encoding="UTF-8"?>"#)?;
+        writeln!(
+            writer,
+            r#"<testsuites name="aoc" tests="{}" failures="{}" errors="{}">"#,
+            tests,
+            count(Outcome::Wrong),
+            errors
+        )?;
+        for day in &self.days {
+            day.write_junit(&mut writer)?;
+        }
+        writeln!(writer, "</testsuites>")
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.chars()
+        .fold(String::with_capacity(s.len()), |mut acc, c| {
+            match c {
+                '&' => acc.push_str("&amp;"),
+                '<' => acc.push_str("&lt;"),
+                '>' => acc.push_str("&gt;"),
+                '"' => acc.push_str("&quot;"),
+                '\'' => acc.push_str("&apos;"),
+                '\n' => acc.push_str("&#10;"),
+                c => acc.push(c),
+            }
+            acc
+        })
+}
@@ -0,0 +1,2 @@
+mod readme;
+mod report;
@@ -0,0 +1,70 @@
+use {crate::*, utils::Answers};
+
+#[test]
+fn test_star_list() {
+    assert_eq!(
+        star_list(&[(2015, 6), (2016, 0), (2017, 50)]),
+        "- [x] [2017](./2017): 50⋆ (100%)\n\
+         - [ ] [2016](./2016): 0⋆\n\
+         - [ ] [2015](./2015): 6⋆ (12%)\n\
+         \n\
+         Total stars: 56⋆ (37.3%)\n"
+    );
+}
+
+#[test]
+fn test_stars() {
+    let answers = "[1]\npart1 = 280\n[5]\npart1 = 1\npart2 = 2"
+        .parse::<Answers>()
+        .unwrap();
+    let solutions = find(Some(2015), None);
+    assert_eq!(stars(&solutions, &answers), 1);
+}
+
+#[test]
+fn test_title() {
+    assert_eq!(
+        title("# Day 3: Perfectly Spherical Houses in a Vacuum ---\n\nSanta"),
+        Some("Day 3: Perfectly Spherical Houses in a Vacuum".into())
+    );
+    assert_eq!(title("No heading"), None);
+}
+
+#[test]
+fn test_puzzle_table() {
+    let entries = [
+        Entry {
+            day: 1,
+            title: "Day 1: Calorie Counting".into(),
+            solutions: vec![
+                ("R".into(), "./01/R/main.R".into()),
+                ("rust".into(), "./01/rust/src/lib.rs".into()),
+            ],
+            stars: 2,
+        },
+        Entry {
+            day: 2,
+            title: "Day 2".into(),
+            solutions: vec![],
+            stars: 0,
+        },
+    ];
+    assert_eq!(
+        puzzle_table(&entries),
+        "| Puzzle                                    | Solution                                         | Stars |\n\
+         |:------------------------------------------|:-------------------------------------------------|:------|\n\
+         | [Day 1: Calorie Counting](./01/README.md) | [R](./01/R/main.R), [rust](./01/rust/src/lib.rs) | ⋆⋆    |\n\
+         | [Day 2](./02/README.md)                   |                                                  |       |\n"
+    );
+}
+
+#[test]
+fn test_replace_section() {
+    let text = "# 2022\n<!-- puzzles -->\nold\n<!-- /puzzles -->\nmore\n";
+    assert_eq!(
+        replace_section(text, "puzzles", "new\n").unwrap(),
+        "# 2022\n<!-- puzzles -->\nnew\n<!-- /puzzles -->\nmore\n"
+    );
+    assert!(replace_section(text, "stars", "").is_err());
+    assert!(replace_section("<!-- stars -->\n", "stars", "").is_err());
+}
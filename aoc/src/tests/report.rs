@@ -0,0 +1,77 @@
+use {
+    crate::*,
+    std::time::Duration,
+    utils::{Answers, Error, Run, Status, Timings},
+};
+
+const ANSWERS: &str = r#"
+[5]
+part1 = "CVCWCRTVQ"
+part2 = "CNSCZWLVT"
+"#;
+
+fn report() -> Report {
+    let answers = ANSWERS.parse::<Answers>().unwrap();
+    let solution = find(Some(2022), Some(5))[0];
+    let run = Run {
+        parts: [Ok("CVCWCRTVQ".into()), Ok("<&>".into())],
+        timings: Timings {
+            parse: Duration::from_millis(1),
+            part1: Duration::from_millis(2),
+            part2: Duration::from_millis(3),
+        },
+    };
+    Report {
+        days: vec![
+            DayReport::new(solution, Ok(run), &answers),
+            DayReport::new(solution, Err(Error::parse(1, 2, "oops")), &answers),
+        ],
+    }
+}
+
+#[test]
+fn test_day_report() {
+    let report = report();
+    let day = &report.days[0];
+    assert_eq!(day.parts[0].status, Outcome::Correct);
+    assert_eq!(day.parts[1].status, Outcome::Wrong);
+    assert_eq!(day.parts[1].expected, Some("CNSCZWLVT".into()));
+    assert_eq!(day.name, "2022 Day 5");
+    assert_eq!(day.parts[0].verdict(), Some(Status::Correct));
+    assert_eq!(
+        day.parts[1].verdict(),
+        Some(Status::Wrong("CNSCZWLVT".into()))
+    );
+    assert!(!day.success());
+    assert!(report.days[1].parts.is_empty());
+    assert!(report.days[1].error.is_some());
+}
+
+#[test]
+fn test_json() {
+    let mut json = Vec::new();
+    report().write_json(&mut json).unwrap();
+    let json: serde_json::Value = serde_json::from_slice(&json).unwrap();
+    let part = &json["days"][0]["parts"][1];
+    assert_eq!(json["days"][0]["year"], 2022);
+    assert_eq!(json["days"][0]["parse"], 0.001);
+    assert_eq!(part["answer"], "<&>");
+    assert_eq!(part["expected"], "CNSCZWLVT");
+    assert_eq!(part["status"], "wrong");
+    assert_eq!(part["time"], 0.003);
+    assert_eq!(
+        json["days"][1]["error"],
+        "Failed to parse line 1, column 2: oops."
+    );
+}
+
+#[test]
+fn test_junit() {
+    let mut xml = Vec::new();
+    report().write_junit(&mut xml).unwrap();
+    let xml = String::from_utf8(xml).unwrap();
+    assert!(xml.contains(r#"<testsuites name="aoc" tests="3" failures="1" errors="1">"#));
+    assert!(xml.contains(r#"<testcase classname="2022.day05" name="part2" time="0.003000">"#));
+    assert!(xml.contains("<system-out>&lt;&amp;&gt;</system-out>"));
+    assert!(xml.contains(r#"<error message="Failed to parse line 1, column 2: oops."/>"#));
+}
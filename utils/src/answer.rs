@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+
+// The answer of a part, whatever type the day solves it with. Numbers and
+// strings are serialized as is, so that answers can be written to and read
+// from `answers.toml` or a JSON report.
+#[derive(Debug, Clone, Eq, Serialize, Deserialize)]
+#[serde(untagged, from = "Raw")]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+    // Letters drawn over several lines, e.g. on a screen
+    Art(String),
+}
+
+// What can be told apart when deserializing: art is any multi-line string
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Raw {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl From<Raw> for Answer {
+    fn from(raw: Raw) -> Self {
+        match raw {
+            Raw::Unsigned(n) => Self::Unsigned(n),
+            Raw::Signed(n) => Self::Signed(n),
+            Raw::Text(s) => s.into(),
+        }
+    }
+}
+
+impl Answer {
+    pub fn is_art(&self) -> bool {
+        matches!(self, Self::Art(_))
+    }
+}
+
+// Numbers are compared by value whatever their signedness, and strings
+// regardless of surrounding blank lines so that art can be written as TOML
+// multi-line strings
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Unsigned(a), Self::Unsigned(b)) => a == b,
+            (Self::Signed(a), Self::Signed(b)) => a == b,
+            (Self::Unsigned(a), Self::Signed(b)) | (Self::Signed(b), Self::Unsigned(a)) => {
+                u64::try_from(*b).is_ok_and(|b| b == *a)
+            }
+            (Self::Text(a) | Self::Art(a), Self::Text(b) | Self::Art(b)) => a.trim() == b.trim(),
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Unsigned(n) => write!(f, "{}", n),
+            Self::Signed(n) => write!(f, "{}", n),
+            Self::Text(s) => write!(f, "{}", s),
+            Self::Art(s) => write!(f, "{}", s.trim_matches('\n')),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($variant:ident, $target:ty, $($source:ty),*) => {
+        $(
+            impl From<$source> for Answer {
+                fn from(n: $source) -> Self {
+                    Self::$variant(n as $target)
+                }
+            }
+        )*
+    };
+}
+
+from_integer!(Unsigned, u64, u8, u16, u32, u64, usize);
+from_integer!(Signed, i64, i8, i16, i32, i64, isize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        if s.trim().contains('\n') {
+            Self::Art(s)
+        } else {
+            Self::Text(s)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        s.to_string().into()
+    }
+}
+
+impl From<char> for Answer {
+    fn from(c: char) -> Self {
+        Self::Text(c.to_string())
+    }
+}
@@ -0,0 +1,88 @@
+use {
+    super::{Answer, Error, Result},
+    serde::Deserialize,
+    std::{collections::BTreeMap, path::Path},
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+struct Parts {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Wrong(Answer),
+    Unknown,
+}
+
+impl std::fmt::Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Correct => write!(f, "correct"),
+            Self::Wrong(expected) if expected.is_art() => {
+                write!(f, "wrong, expected:\n{}", expected)
+            }
+            Self::Wrong(expected) => write!(f, "wrong, expected {}", expected),
+            Self::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+// Known answers of a year, keyed by day then part:
+//
+// [10]
+// part1 = 12520
+// part2 = """
+// ####.#..#.###..
+// ...
+// """
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<u8, Parts>);
+
+impl Answers {
+    // A missing file simply means that no answer is known yet
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(s) => s.parse(),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.0.get(&day).and_then(|parts| match part {
+            1 => parts.part1.as_ref(),
+            2 => parts.part2.as_ref(),
+            _ => None,
+        })
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Status {
+        match self.get(day, part) {
+            Some(expected) if expected == answer => Status::Correct,
+            Some(expected) => Status::Wrong(expected.clone()),
+            None => Status::Unknown,
+        }
+    }
+}
+
+impl std::str::FromStr for Answers {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let days: BTreeMap<String, Parts> = toml::from_str(s).map_err(|err| {
+            let offset = err.span().map_or(0, |span| span.start);
+            Error::parse_at(s, offset, err.message())
+        })?;
+        days.into_iter()
+            .map(|(day, parts)| {
+                day.parse::<u8>()
+                    .map(|day| (day, parts))
+                    .map_err(|_| Error::parse_at(s, s.find(&day).unwrap_or(0), "invalid day"))
+            })
+            .collect::<Result<_>>()
+            .map(Self)
+    }
+}
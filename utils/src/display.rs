@@ -0,0 +1,112 @@
+use {
+    super::*,
+    std::{collections::HashSet, fmt},
+};
+
+// Renders the points from `min` to `max` as lines of characters. Rows are
+// written from the smallest `y` down, as grids are parsed, unless `y_up` is
+// set to follow `Point`'s convention.
+pub struct Render<'a> {
+    min: Point,
+    max: Point,
+    cell: Box<dyn Fn(Point) -> char + 'a>,
+    overlays: Vec<(HashSet<Point>, char)>,
+    axes: bool,
+    y_up: bool,
+}
+
+impl<'a> Render<'a> {
+    pub fn new(min: Point, max: Point, cell: impl Fn(Point) -> char + 'a) -> Self {
+        Self {
+            min,
+            max,
+            cell: Box::new(cell),
+            overlays: Vec::new(),
+            axes: false,
+            y_up: false,
+        }
+    }
+
+    // Draws `c` over the given points, e.g. a path, later overlays on top
+    pub fn overlay(mut self, points: impl IntoIterator<Item = Point>, c: char) -> Self {
+        self.overlays.push((points.into_iter().collect(), c));
+        self
+    }
+
+    // Labels the columns above the rows, vertically, and the rows on their left
+    pub fn axes(mut self) -> Self {
+        self.axes = true;
+        self
+    }
+
+    pub fn y_up(mut self) -> Self {
+        self.y_up = true;
+        self
+    }
+
+    fn char_at(&self, point: Point) -> char {
+        self.overlays
+            .iter()
+            .rev()
+            .find_map(|(points, c)| points.contains(&point).then_some(*c))
+            .unwrap_or_else(|| (self.cell)(point))
+    }
+}
+
+impl fmt::Display for Render<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (Point(min_x, min_y), Point(max_x, max_y)) = (self.min, self.max);
+        let rows: Box<dyn Iterator<Item = isize>> = if self.y_up {
+            Box::new((min_y..=max_y).rev())
+        } else {
+            Box::new(min_y..=max_y)
+        };
+        let width = [min_y, max_y]
+            .iter()
+            .map(|y| y.to_string().len())
+            .max()
+            .unwrap_or(0);
+        if self.axes && min_x <= max_x {
+            let labels = (min_x..=max_x)
+                .map(|x| x.to_string().chars().collect::<Vec<_>>())
+                .collect::<Vec<_>>();
+            let height = labels.iter().map(Vec::len).max().unwrap_or(0);
+            for line in 0..height {
+                write!(f, "{:width$} ", "", width = width)?;
+                for label in &labels {
+                    let c = (line + label.len())
+                        .checked_sub(height)
+                        .and_then(|i| label.get(i));
+                    write!(f, "{}", c.unwrap_or(&' '))?;
+                }
+                writeln!(f)?;
+            }
+        }
+        for y in rows {
+            if self.axes {
+                write!(f, "{:>width$} ", y, width = width)?;
+            }
+            for x in min_x..=max_x {
+                write!(f, "{}", self.char_at(Point(x, y)))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn display<'a>(&'a self, cell: impl Fn(&T) -> char + 'a) -> Render<'a> {
+        Render::new(self.min(), self.max(), move |point| {
+            self.get(point).map_or(' ', &cell)
+        })
+    }
+}
+
+impl<T, S: Storage<T>> SparseGrid<T, S> {
+    // Renders the bounding box of the points, `None` standing for the missing ones
+    pub fn display<'a>(&'a self, cell: impl Fn(Option<&T>) -> char + 'a) -> Render<'a> {
+        let (min, max) = self.extent();
+        Render::new(min, max, move |point| cell(self.get(&point)))
+    }
+}
@@ -0,0 +1,131 @@
+use {
+    super::*,
+    std::{
+        collections::{HashMap, HashSet},
+        fmt,
+    },
+};
+
+// A Graphviz graph whose nodes are points, pinned where they lie on the grid,
+// the first row on top. Render it with `neato -Tsvg`, which honours positions.
+pub struct Dot {
+    nodes: Vec<(Point, String)>,
+    edges: Vec<(Point, Point)>,
+    fills: Vec<(HashSet<Point>, String)>,
+    paths: Vec<(HashSet<(Point, Point)>, String)>,
+}
+
+impl Dot {
+    pub fn new(
+        nodes: impl IntoIterator<Item = (Point, String)>,
+        edges: impl IntoIterator<Item = (Point, Point)>,
+    ) -> Self {
+        Self {
+            nodes: nodes.into_iter().collect(),
+            edges: edges.into_iter().collect(),
+            fills: Vec::new(),
+            paths: Vec::new(),
+        }
+    }
+
+    // Fills the given nodes, e.g. those visited by a search, later fills on top
+    pub fn visited(mut self, points: impl IntoIterator<Item = Point>, colour: &str) -> Self {
+        self.fills
+            .push((points.into_iter().collect(), colour.to_string()));
+        self
+    }
+
+    // Fills the nodes of a path and draws the edges between them in bold
+    pub fn path(self, points: &[Point], colour: &str) -> Self {
+        let mut dot = self.visited(points.iter().copied(), colour);
+        dot.paths.push((
+            points.windows(2).map(|pair| (pair[0], pair[1])).collect(),
+            colour.to_string(),
+        ));
+        dot
+    }
+
+    fn id(Point(x, y): Point) -> String {
+        format!("\"{},{}\"", x, y)
+    }
+}
+
+fn escape(label: &str) -> String {
+    label.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+impl fmt::Display for Dot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "digraph {{")?;
+        writeln!(
+            f,
+            "    node [shape = square, fixedsize = true, width = 0.5]"
+        )?;
+        let fills = self
+            .nodes
+            .iter()
+            .filter_map(|(point, _)| {
+                self.fills
+                    .iter()
+                    .rev()
+                    .find(|(points, _)| points.contains(point))
+                    .map(|(_, colour)| (*point, colour))
+            })
+            .collect::<HashMap<_, _>>();
+        for (point, label) in &self.nodes {
+            write!(
+                f,
+                "    {} [label = \"{}\", pos = \"{},{}!\"",
+                Self::id(*point),
+                escape(label),
+                point.0,
+                -point.1
+            )?;
+            if let Some(colour) = fills.get(point) {
+                write!(f, ", style = filled, fillcolor = \"{}\"", escape(colour))?;
+            }
+            writeln!(f, "]")?;
+        }
+        for (from, to) in &self.edges {
+            write!(f, "    {} -> {}", Self::id(*from), Self::id(*to))?;
+            let path = self
+                .paths
+                .iter()
+                .rev()
+                .find(|(edges, _)| edges.contains(&(*from, *to)));
+            if let Some((_, colour)) = path {
+                write!(f, " [color = \"{}\", penwidth = 3]", escape(colour))?;
+            }
+            writeln!(f)?;
+        }
+        writeln!(f, "}}")
+    }
+}
+
+impl<T> Grid<T> {
+    // A node per cell, labelled by `label`, with an edge to each successor
+    pub fn dot<I>(
+        &self,
+        label: impl Fn(&T) -> String,
+        mut successors: impl FnMut(&Point) -> I,
+    ) -> Dot
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        let points = self.points();
+        let edges = points
+            .iter()
+            .flat_map(|from| {
+                successors(from)
+                    .into_iter()
+                    .map(move |to| (*from, to))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        let nodes = points
+            .into_iter()
+            .zip(&self.values)
+            .map(|(point, value)| (point, label(value)));
+        Dot::new(nodes, edges)
+    }
+}
@@ -0,0 +1,58 @@
+#[derive(Debug, thiserror::Error)]
+pub enum Error {
+    #[error("Failed to read input: {0}.")]
+    Io(#[from] std::io::Error),
+
+    #[error("Failed to parse line {line}, column {column}: {message}.")]
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+    },
+
+    #[error("Failed to find a solution: {0}.")]
+    NoSolution(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl Error {
+    // Lines and columns are counted from 1, like in any text editor
+    pub fn parse(line: usize, column: usize, message: impl ToString) -> Self {
+        Self::Parse {
+            line,
+            column,
+            message: message.to_string(),
+        }
+    }
+
+    // Locates the byte `offset` of `input`, e.g. where a parser gave up
+    pub fn parse_at(input: &str, offset: usize, message: impl ToString) -> Self {
+        let before = &input[..offset.min(input.len())];
+        let line = before.matches('\n').count() + 1;
+        let column = before.chars().rev().take_while(|c| *c != '\n').count() + 1;
+        Self::parse(line, column, message)
+    }
+
+    // Locates the remaining `rest` of `input`, e.g. what a parser failed to consume
+    pub fn parse_rest(input: &str, rest: &str, message: impl ToString) -> Self {
+        Self::parse_at(input, input.len().saturating_sub(rest.len()), message)
+    }
+
+    pub fn no_solution(message: impl ToString) -> Self {
+        Self::NoSolution(message.to_string())
+    }
+}
+
+// Parses each line of `input`, reporting the line of the first failure
+pub fn parse_lines<T, E: std::fmt::Display>(
+    input: &str,
+    mut parse: impl FnMut(&str) -> Result<T, E>,
+) -> Result<Vec<T>> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse(line).map_err(|err| Error::parse(i + 1, 1, err)))
+        .collect()
+}
@@ -1,9 +1,13 @@
-use super::*;
+use {super::*, std::iter::StepBy};
 
+// Cells are addressed from `Point(0, 0)`, the top left one. A grid built from
+// a larger plane, e.g. a `SparseGrid`, remembers where that cell lies on it as
+// its `origin`.
 #[derive(PartialEq, Eq, Clone, Default, Debug)]
 pub struct Grid<T> {
-    max: Point,
-    values: Vec<T>,
+    pub(crate) max: Point,
+    pub(crate) values: Vec<T>,
+    pub(crate) origin: Point,
 }
 
 impl<T> Grid<T> {
@@ -15,18 +19,41 @@ impl<T> Grid<T> {
         self.max
     }
 
+    pub fn origin(&self) -> Point {
+        self.origin
+    }
+
+    pub fn with_origin(mut self, origin: Point) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    // Where a cell of the grid lies on the plane it was taken from
+    pub fn to_plane(&self, point: Point) -> Point {
+        point + self.origin
+    }
+
+    // The cell of the grid at a point of the plane it was taken from
+    pub fn from_plane(&self, point: Point) -> Point {
+        point - self.origin
+    }
+
     pub fn cols(&self) -> usize {
-        self.max.0 as usize + 1
+        (self.max.0 + 1) as usize
     }
 
     pub fn rows(&self) -> usize {
-        self.max.1 as usize + 1
+        (self.max.1 + 1) as usize
     }
 
     pub fn len(&self) -> usize {
         self.values.len()
     }
 
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
     pub fn index(&self, Point(col, row): Point) -> usize {
         col as usize + row as usize * self.cols()
     }
@@ -51,65 +78,133 @@ impl<T> Grid<T> {
         let Point(max_col, max_row) = self.max;
         row == 0 || col == 0 || col == max_col || row == max_row
     }
-    pub fn get_row(&self, row: usize) -> Option<Vec<&T>> {
-        println!("{} vs {}", row, self.rows());
-        (row < self.rows()).then(|| {
-            (0..self.cols())
-                .map(move |col| self.get((col, row).into()).unwrap())
-                .collect()
-        })
+    pub fn get_row(&self, row: usize) -> Option<&[T]> {
+        let cols = self.cols();
+        (row < self.rows()).then(|| &self.values[row * cols..(row + 1) * cols])
     }
 
-    pub fn get_col(&self, col: usize) -> Option<Vec<&T>> {
-        (col < self.cols()).then(|| {
-            (0..self.rows())
-                .map(move |row| self.get((row, col).into()).unwrap())
-                .collect()
-        })
+    pub fn get_col(&self, col: usize) -> Option<StepBy<std::slice::Iter<'_, T>>> {
+        (col < self.cols()).then(|| self.values[col..].iter().step_by(self.cols()))
     }
 
-    pub fn apply<F: FnMut(&T)>(&self, fun: F) {
-        self.values.iter().for_each(fun);
+    pub fn iter_rows(&self) -> std::slice::Chunks<'_, T> {
+        self.values.chunks(self.cols().max(1))
     }
 
-    pub fn apply_mut<F: FnMut(&mut T)>(&mut self, fun: F) {
-        self.values.iter_mut().for_each(fun);
+    pub fn iter_cols(&self) -> impl Iterator<Item = StepBy<std::slice::Iter<'_, T>>> {
+        (0..self.cols()).filter_map(|col| self.get_col(col))
     }
 
-    pub fn insert(&mut self, Point(row, col): Point, value: T) {
-        if let Some(cell) = self.get_mut(Point(row, col)) {
-            *cell = value;
+    // Cells met when walking from `from` (excluded) towards `direction`, as
+    // `from + Step(direction, n)`, until the edge of the grid
+    pub fn ray(&self, from: Point, direction: Direction) -> Ray<'_, T> {
+        Ray {
+            grid: self,
+            next: from + Step(direction, 1),
+            direction,
         }
     }
 
-    pub fn insert_at(&mut self, index: usize, value: T) {
-        self.values.insert(index, value)
+    // Every line of the grid walked towards `direction`, each one starting on
+    // the edge: rows for `East`, columns for `North`, diagonals for `NorthEast`...
+    pub fn lines(&self, direction: Direction) -> impl Iterator<Item = Ray<'_, T>> {
+        (0..self.rows() as isize)
+            .flat_map(move |row| (0..self.cols() as isize).map(move |col| Point(col, row)))
+            .filter(move |point| !self.contains(&(*point - Step(direction, 1))))
+            .map(move |start| Ray {
+                grid: self,
+                next: start,
+                direction,
+            })
     }
-}
 
-impl<T: Clone> Grid<T> {
-    pub fn transpose(&self) -> Self {
-        Self {
-            max: self.max,
-            values: Vec::from_iter(
-                (0..self.rows())
-                    .flat_map(move |row| self.get_row(row).unwrap().into_iter().cloned())
-                    .collect::<Vec<_>>(),
-            ),
-        }
+    // Neighbours of `point` that lie within the grid
+    pub fn neighbours(
+        &self,
+        point: Point,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = (Point, &T)> {
+        neighbourhood
+            .points(point)
+            .filter_map(|neighbour| self.get(neighbour).map(|value| (neighbour, value)))
+    }
+
+    // Neighbours of `point` on a torus, where leaving the grid on one edge
+    // enters it again from the opposite edge
+    pub fn neighbours_wrapping(
+        &self,
+        point: Point,
+        neighbourhood: Neighbourhood,
+    ) -> impl Iterator<Item = (Point, &T)> {
+        neighbourhood.points(point).filter_map(|neighbour| {
+            let neighbour = self.wrap(neighbour);
+            self.get(neighbour).map(|value| (neighbour, value))
+        })
+    }
+
+    // Every neighbour of `point`, where those outside the grid hold `sentinel`
+    pub fn neighbours_or<'a>(
+        &'a self,
+        point: Point,
+        neighbourhood: Neighbourhood,
+        sentinel: &'a T,
+    ) -> impl Iterator<Item = (Point, &'a T)> {
+        neighbourhood
+            .points(point)
+            .map(move |neighbour| (neighbour, self.get(neighbour).unwrap_or(sentinel)))
+    }
+
+    // Brings a point back within the grid as if its edges were glued together
+    pub fn wrap(&self, Point(col, row): Point) -> Point {
+        Point(
+            col.rem_euclid(self.cols().max(1) as isize),
+            row.rem_euclid(self.rows().max(1) as isize),
+        )
     }
 
     pub fn points(&self) -> Vec<Point> {
         (self.min().1..=self.max().1)
             .flat_map(move |y| {
                 (self.min().0..=self.max().0)
-                    .into_iter()
                     .map(|x| Point(x, y))
                     .collect::<Vec<Point>>()
             })
             .collect()
     }
 
+    // Builds a grid from the value of each of its points, in reading order
+    pub fn from_fn(cols: usize, rows: usize, mut fun: impl FnMut(Point) -> T) -> Self {
+        let values = (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| Point(col as isize, row as isize)))
+            .map(&mut fun)
+            .collect();
+        Self {
+            max: Point(cols as isize - 1, rows as isize - 1),
+            values,
+            origin: Point::default(),
+        }
+    }
+
+    pub fn apply<F: FnMut(&T)>(&self, fun: F) {
+        self.values.iter().for_each(fun);
+    }
+
+    pub fn apply_mut<F: FnMut(&mut T)>(&mut self, fun: F) {
+        self.values.iter_mut().for_each(fun);
+    }
+
+    pub fn insert(&mut self, Point(row, col): Point, value: T) {
+        if let Some(cell) = self.get_mut(Point(row, col)) {
+            *cell = value;
+        }
+    }
+
+    pub fn insert_at(&mut self, index: usize, value: T) {
+        self.values.insert(index, value)
+    }
+}
+
+impl<T: Clone> Grid<T> {
     pub fn values(&self) -> Vec<T> {
         self.values.clone()
     }
@@ -124,21 +219,48 @@ impl<T: Default + Clone> Grid<T> {
         Self {
             max: Point(cols as isize - 1, rows as isize - 1),
             values: vec![T::default(); rows * cols],
+            origin: Point::default(),
         }
     }
 }
 
-impl<T: Default + Clone> From<SparseGrid<T>> for Grid<T> {
-    fn from(sparse: SparseGrid<T>) -> Self {
-        let Point(cols, rows) = sparse.max();
-        let mut dense: Grid<T> = Self::new(cols as usize, rows as usize);
-        for (point, value) in sparse.into_iter() {
-            dense.insert(point, value);
+// Covers the bounds of the sparse grid, its missing points holding the default
+// value, with its smallest point as the origin
+impl<T: Default + Clone, S: Storage<T>> From<SparseGrid<T, S>> for Grid<T> {
+    fn from(sparse: SparseGrid<T, S>) -> Self {
+        let (min, max) = sparse.extent();
+        let size = max - min + Point(1, 1);
+        let mut dense = Self::new(size.0 as usize, size.1 as usize).with_origin(min);
+        for (point, value) in sparse {
+            dense.insert(point - min, value);
         }
         dense
     }
 }
 
+// Every cell, placed back on the plane
+impl<T, S: Storage<T>> From<Grid<T>> for SparseGrid<T, S> {
+    fn from(grid: Grid<T>) -> Self {
+        let origin = grid.origin;
+        grid.points()
+            .into_iter()
+            .map(|point| point + origin)
+            .zip(grid.values)
+            .collect()
+    }
+}
+
+impl<T: Default + PartialEq> Grid<T> {
+    // Like `SparseGrid::from`, without the cells holding the default value
+    pub fn into_sparse_skipping_default<S: Storage<T>>(self) -> SparseGrid<T, S> {
+        let default = T::default();
+        SparseGrid::<T, S>::from(self)
+            .into_iter()
+            .filter(|(_, value)| *value != default)
+            .collect()
+    }
+}
+
 impl<T: Default + Clone + PartialEq> Grid<T> {
     pub fn empty(&self) -> usize {
         self.values.iter().filter(|v| **v == T::default()).count()
@@ -152,15 +274,116 @@ impl<T: Default + Clone + PartialEq> Grid<T> {
 impl<T> From<Vec<Vec<T>>> for Grid<T> {
     fn from(grid: Vec<Vec<T>>) -> Self {
         let rows = grid.len();
-        let cols = grid.get(0).unwrap().len();
+        let cols = grid.first().unwrap().len();
         let values = grid.into_iter().flatten().collect();
         Self {
             max: Point(cols as isize - 1, rows as isize - 1),
             values,
+            origin: Point::default(),
         }
     }
 }
 
+impl<T> Grid<T> {
+    // Maps each character of a rectangular map to a cell, ignoring blank lines
+    // around it, and reports the position of the first invalid character
+    pub fn parse<E: std::fmt::Display>(
+        s: &str,
+        cell: impl Fn(char) -> Result<T, E>,
+    ) -> Result<Self> {
+        let lines = s.lines().collect::<Vec<_>>();
+        let Some(first) = lines.iter().position(|line| !line.trim().is_empty()) else {
+            return Err(Error::parse(1, 1, "empty grid"));
+        };
+        let last = lines
+            .iter()
+            .rposition(|line| !line.trim().is_empty())
+            .unwrap_or(first);
+        let cols = lines[first].chars().count();
+        let mut values = Vec::with_capacity(cols * (last - first + 1));
+        for (i, line) in lines.iter().enumerate().take(last + 1).skip(first) {
+            let mut len = 0;
+            for (j, c) in line.chars().enumerate() {
+                if j == cols {
+                    return Err(Error::parse(
+                        i + 1,
+                        j + 1,
+                        format!("expected {} columns", cols),
+                    ));
+                }
+                values.push(cell(c).map_err(|err| Error::parse(i + 1, j + 1, err))?);
+                len += 1;
+            }
+            if len < cols {
+                return Err(Error::parse(
+                    i + 1,
+                    len + 1,
+                    format!("expected {} columns, found {}", cols, len),
+                ));
+            }
+        }
+        Ok(Self {
+            max: Point(cols as isize - 1, (last - first) as isize),
+            values,
+            origin: Point::default(),
+        })
+    }
+}
+
+impl<T: TryFrom<char>> std::str::FromStr for Grid<T>
+where
+    T::Error: std::fmt::Display,
+{
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, T::try_from)
+    }
+}
+
+// Which cells surround a point
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Neighbourhood {
+    // The 4 orthogonal ones
+    VonNeumann,
+    // The 8 orthogonal and diagonal ones
+    Moore,
+}
+
+impl Neighbourhood {
+    // Clockwise, starting from the north
+    pub fn directions(self) -> impl Iterator<Item = Direction> {
+        let step = match self {
+            Self::VonNeumann => 2,
+            Self::Moore => 1,
+        };
+        DIRECTIONS.into_iter().step_by(step)
+    }
+
+    pub fn points(self, point: Point) -> impl Iterator<Item = Point> {
+        self.directions()
+            .map(move |direction| point + Step(direction, 1))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Ray<'a, T> {
+    grid: &'a Grid<T>,
+    next: Point,
+    direction: Direction,
+}
+
+impl<'a, T> Iterator for Ray<'a, T> {
+    type Item = (Point, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let point = self.next;
+        let value = self.grid.get(point)?;
+        self.next = point + Step(self.direction, 1);
+        Some((point, value))
+    }
+}
+
 impl<T> std::ops::Deref for Grid<T> {
     type Target = Vec<T>;
 
@@ -1,53 +1,197 @@
 use {
     super::Point,
     std::{
-        collections::{btree_map::Entry, BTreeMap},
-        usize,
+        collections::{btree_map, hash_map, BTreeMap, HashMap},
+        marker::PhantomData,
     },
 };
 
-#[derive(PartialEq, Eq, Clone, Default, Debug)]
-pub struct SparseGrid<T> {
-    max: Point,
-    min: Point,
-    values: BTreeMap<Point, T>,
+// Where a sparse grid keeps its values: a `BTreeMap`, ordered by column then
+// row, or a `HashMap`, faster when the order doesn't matter
+pub trait Storage<T>: Default + IntoIterator<Item = (Point, T)> {
+    type Iter<'a>: Iterator<Item = (&'a Point, &'a T)>
+    where
+        Self: 'a,
+        T: 'a;
+    type IterMut<'a>: Iterator<Item = (&'a Point, &'a mut T)>
+    where
+        Self: 'a,
+        T: 'a;
+
+    fn get(&self, point: &Point) -> Option<&T>;
+    fn get_mut(&mut self, point: &Point) -> Option<&mut T>;
+    fn insert(&mut self, point: Point, value: T) -> Option<T>;
+    fn remove(&mut self, point: &Point) -> Option<T>;
+    fn len(&self) -> usize;
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    fn iter(&self) -> Self::Iter<'_>;
+    fn iter_mut(&mut self) -> Self::IterMut<'_>;
+
+    // The values from `min` to `max` included
+    fn range<'a>(&'a self, min: Point, max: Point) -> impl Iterator<Item = (&'a Point, &'a T)>
+    where
+        T: 'a,
+    {
+        self.iter()
+            .filter(move |(point, _)| point.is_within(&min, &max))
+    }
+}
+
+impl<T> Storage<T> for BTreeMap<Point, T> {
+    type Iter<'a>
+        = btree_map::Iter<'a, Point, T>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = btree_map::IterMut<'a, Point, T>
+    where
+        T: 'a;
+
+    fn get(&self, point: &Point) -> Option<&T> {
+        BTreeMap::get(self, point)
+    }
+
+    fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        BTreeMap::get_mut(self, point)
+    }
+
+    fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        BTreeMap::insert(self, point, value)
+    }
+
+    fn remove(&mut self, point: &Point) -> Option<T> {
+        BTreeMap::remove(self, point)
+    }
+
+    fn len(&self) -> usize {
+        BTreeMap::len(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        BTreeMap::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        BTreeMap::iter_mut(self)
+    }
+
+    // Only visits the columns from `min` to `max`
+    fn range<'a>(&'a self, min: Point, max: Point) -> impl Iterator<Item = (&'a Point, &'a T)>
+    where
+        T: 'a,
+    {
+        let columns = if min <= max {
+            BTreeMap::range(self, min..=max)
+        } else {
+            BTreeMap::range(self, min..min)
+        };
+        columns.filter(move |(point, _)| point.is_within(&min, &max))
+    }
+}
+
+impl<T> Storage<T> for HashMap<Point, T> {
+    type Iter<'a>
+        = hash_map::Iter<'a, Point, T>
+    where
+        T: 'a;
+    type IterMut<'a>
+        = hash_map::IterMut<'a, Point, T>
+    where
+        T: 'a;
+
+    fn get(&self, point: &Point) -> Option<&T> {
+        HashMap::get(self, point)
+    }
+
+    fn get_mut(&mut self, point: &Point) -> Option<&mut T> {
+        HashMap::get_mut(self, point)
+    }
+
+    fn insert(&mut self, point: Point, value: T) -> Option<T> {
+        HashMap::insert(self, point, value)
+    }
+
+    fn remove(&mut self, point: &Point) -> Option<T> {
+        HashMap::remove(self, point)
+    }
+
+    fn len(&self) -> usize {
+        HashMap::len(self)
+    }
+
+    fn iter(&self) -> Self::Iter<'_> {
+        HashMap::iter(self)
+    }
+
+    fn iter_mut(&mut self) -> Self::IterMut<'_> {
+        HashMap::iter_mut(self)
+    }
 }
 
+fn extend(bounds: &mut Option<(Point, Point)>, point: &Point) {
+    let (min, max) = bounds.get_or_insert((*point, *point));
+    *min = Point(min.0.min(point.0), min.1.min(point.1));
+    *max = Point(max.0.max(point.0), max.1.max(point.1));
+}
+
+// Values scattered on an infinite plane, bounded by the points inserted so far
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct SparseGrid<T, S = BTreeMap<Point, T>> {
+    bounds: Option<(Point, Point)>,
+    values: S,
+    marker: PhantomData<T>,
+}
+
+pub type HashSparseGrid<T> = SparseGrid<T, HashMap<Point, T>>;
+
+// Only the default storage gets a `new`, a second one would leave
+// `SparseGrid::new()` ambiguous: start a `HashSparseGrid` with `default` or
+// `with_capacity` instead
 impl<T> SparseGrid<T> {
     pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T> HashSparseGrid<T> {
+    pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            max: Point::default(),
-            min: Point::default(),
-            values: BTreeMap::new(),
+            bounds: None,
+            values: HashMap::with_capacity(capacity),
+            marker: PhantomData,
         }
     }
+}
 
-    pub fn min(&self) -> Point {
-        self.min
+impl<T, S: Storage<T>> Default for SparseGrid<T, S> {
+    fn default() -> Self {
+        Self {
+            bounds: None,
+            values: S::default(),
+            marker: PhantomData,
+        }
     }
+}
 
-    pub fn max(&self) -> Point {
-        self.max
+impl<T, S: Storage<T>> SparseGrid<T, S> {
+    // The smallest and largest coordinates of the points, if any
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        self.bounds
     }
 
-    pub fn update_limits(&mut self, point: &Point) {
-        if point.0 > self.max.0 {
-            self.max.0 = point.0;
-        }
-        if point.1 > self.max.1 {
-            self.max.1 = point.1;
-        }
-        if point.0 < self.min.0 {
-            self.min.0 = point.0;
-        }
-        if point.1 < self.min.1 {
-            self.min.1 = point.1;
-        }
+    pub fn min(&self) -> Option<Point> {
+        self.bounds.map(|(min, _)| min)
     }
 
-    pub fn entry(&mut self, point: Point) -> Entry<Point, T> {
-        self.update_limits(&point);
-        self.values.entry(point)
+    pub fn max(&self) -> Option<Point> {
+        self.bounds.map(|(_, max)| max)
+    }
+
+    // The bounds, or an empty rectangle when there is no point
+    pub(crate) fn extent(&self) -> (Point, Point) {
+        self.bounds.unwrap_or((Point::default(), Point(-1, -1)))
     }
 
     pub fn get(&self, point: &Point) -> Option<&T> {
@@ -59,10 +203,24 @@ impl<T> SparseGrid<T> {
     }
 
     pub fn insert(&mut self, point: Point, value: T) -> Option<T> {
-        self.update_limits(&point);
+        extend(&mut self.bounds, &point);
         self.values.insert(point, value)
     }
 
+    // Shrinks the bounds when the point was on their edge
+    pub fn remove(&mut self, point: &Point) -> Option<T> {
+        let value = self.values.remove(point)?;
+        if let Some((min, max)) = self.bounds {
+            if [min.0, max.0].contains(&point.0) || [min.1, max.1].contains(&point.1) {
+                self.bounds = None;
+                for (point, _) in self.values.iter() {
+                    extend(&mut self.bounds, point);
+                }
+            }
+        }
+        Some(value)
+    }
+
     pub fn len(&self) -> usize {
         self.values.len()
     }
@@ -72,33 +230,62 @@ impl<T> SparseGrid<T> {
     }
 
     fn can_contain_point(&self, point: &Point) -> bool {
-        point.0 > self.max.0 || point.1 > self.max.1 || point.0 < self.min.0 || point.1 < self.min.1
+        self.bounds
+            .is_some_and(|(min, max)| point.is_within(&min, &max))
     }
 
     pub fn contains_point(&self, point: &Point) -> bool {
-        self.can_contain_point(point) && self.values.contains_key(point)
+        self.can_contain_point(point) && self.values.get(point).is_some()
+    }
+
+    pub fn iter(&self) -> S::Iter<'_> {
+        self.values.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> S::IterMut<'_> {
+        self.values.iter_mut()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &Point> {
+        self.values.iter().map(|(point, _)| point)
+    }
+
+    // The points from `min` to `max` included, with their values
+    pub fn range(&self, min: Point, max: Point) -> impl Iterator<Item = (&Point, &T)> {
+        self.values.range(min, max)
     }
 }
 
-impl<T: num_traits::NumAssign> SparseGrid<T> {
+impl<T: num_traits::NumAssign, S: Storage<T>> SparseGrid<T, S> {
     pub fn increment(&mut self, point: Point) {
-        self.entry(point)
-            .and_modify(|t| *t += num_traits::one())
-            .or_insert_with(num_traits::one);
+        match self.get_mut(&point) {
+            Some(t) => *t += num_traits::one(),
+            None => {
+                self.insert(point, num_traits::one());
+            }
+        }
     }
 }
 
-impl<T: num_traits::NumAssign> std::ops::AddAssign<Point> for SparseGrid<T> {
+impl<T: num_traits::NumAssign, S: Storage<T>> std::ops::AddAssign<Point> for SparseGrid<T, S> {
     fn add_assign(&mut self, rhs: Point) {
-        self.entry(rhs)
-            .and_modify(|t| *t += num_traits::one())
-            .or_insert_with(num_traits::one);
+        self.increment(rhs);
+    }
+}
+
+impl<T, S: Storage<T>> FromIterator<(Point, T)> for SparseGrid<T, S> {
+    fn from_iter<I: IntoIterator<Item = (Point, T)>>(values: I) -> Self {
+        let mut sparse = Self::default();
+        for (point, value) in values {
+            sparse.insert(point, value);
+        }
+        sparse
     }
 }
 
-impl<T> IntoIterator for SparseGrid<T> {
+impl<T, S: Storage<T>> IntoIterator for SparseGrid<T, S> {
     type Item = (Point, T);
-    type IntoIter = std::collections::btree_map::IntoIter<Point, T>;
+    type IntoIter = S::IntoIter;
 
     fn into_iter(self) -> Self::IntoIter {
         self.values.into_iter()
@@ -0,0 +1,59 @@
+use {
+    super::{Error, Result, Solution},
+    std::path::PathBuf,
+};
+
+// Overrides the location of every input, laid out as `<dir>/2022/07/input`
+pub const INPUT_DIR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Path(PathBuf),
+    Stdin,
+}
+
+impl Input {
+    // `AOC_INPUT_DIR` if set, else the `input` next to the day's README
+    pub fn locate(solution: &Solution) -> Self {
+        let dir = match std::env::var_os(INPUT_DIR) {
+            Some(dir) => PathBuf::from(dir)
+                .join(solution.year.to_string())
+                .join(format!("{:02}", solution.day)),
+            None => PathBuf::from(solution.manifest_dir).join(".."),
+        };
+        Self::Path(dir.join("input"))
+    }
+
+    pub fn read(&self) -> Result<String> {
+        match self {
+            Self::Path(path) => std::fs::read_to_string(path).map_err(|err| {
+                Error::Io(std::io::Error::new(
+                    err.kind(),
+                    format!("{}: {}", path.display(), err),
+                ))
+            }),
+            Self::Stdin => Ok(std::io::read_to_string(std::io::stdin())?),
+        }
+    }
+}
+
+// `-` stands for the standard input, anything else for a path
+impl std::str::FromStr for Input {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "-" => Self::Stdin,
+            path => Self::Path(path.into()),
+        })
+    }
+}
+
+impl std::fmt::Display for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Path(path) => write!(f, "{}", path.display()),
+            Self::Stdin => write!(f, "-"),
+        }
+    }
+}
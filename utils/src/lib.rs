@@ -1,14 +1,65 @@
+mod error;
+pub use error::*;
+
+mod answer;
+pub use answer::*;
+
+mod answers;
+pub use answers::*;
+
+mod input;
+pub use input::*;
+
 mod puzzle;
 pub use puzzle::*;
 
 mod point;
 pub use point::*;
 
+mod point_n;
+pub use point_n::*;
+
 mod step;
 pub use step::*;
 
 mod grid;
 pub use grid::*;
 
+mod display;
+pub use display::*;
+
+mod dot;
+pub use dot::*;
+
+mod render;
+pub use render::*;
+
+mod record;
+pub use record::*;
+
+mod region;
+pub use region::*;
+
+mod transform;
+pub use transform::*;
+
 mod grid_sparse;
 pub use grid_sparse::*;
+
+mod ocr;
+pub use ocr::*;
+
+mod search;
+pub use search::*;
+
+mod samples;
+pub use samples::*;
+
+mod solution;
+pub use solution::*;
+
+mod timing;
+pub use timing::*;
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,144 @@
+use super::{Error, Grid, Point, Result};
+
+// The block letters drawn by the puzzles, 4 pixels wide and 6 high...
+const SMALL: [(char, [&str; 6]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...#", "#...#", ".#.#.", "..#..", "..#..", "..#.."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+// ...and the larger ones, 6 pixels wide and 10 high
+#[rustfmt::skip]
+const LARGE: [(char, [&str; 10]); 15] = [
+    ('A', ["..##..", ".#..#.", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#"]),
+    ('B', ["#####.", "#....#", "#....#", "#....#", "#####.", "#....#", "#....#", "#....#", "#....#", "#####."]),
+    ('C', [".####.", "#....#", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#....#", ".####."]),
+    ('E', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('F', ["######", "#.....", "#.....", "#.....", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('G', [".####.", "#....#", "#.....", "#.....", "#.....", "#..###", "#....#", "#....#", "#...##", ".###.#"]),
+    ('H', ["#....#", "#....#", "#....#", "#....#", "######", "#....#", "#....#", "#....#", "#....#", "#....#"]),
+    ('J', ["...###", "....#.", "....#.", "....#.", "....#.", "....#.", "....#.", "#...#.", "#...#.", ".###.."]),
+    ('K', ["#....#", "#...#.", "#..#..", "#.#...", "##....", "##....", "#.#...", "#..#..", "#...#.", "#....#"]),
+    ('L', ["#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "#.....", "######"]),
+    ('N', ["#....#", "##...#", "##...#", "#.#..#", "#.#..#", "#..#.#", "#..#.#", "#...##", "#...##", "#....#"]),
+    ('P', ["#####.", "#....#", "#....#", "#....#", "#####.", "#.....", "#.....", "#.....", "#.....", "#....."]),
+    ('R', ["#####.", "#....#", "#....#", "#....#", "#####.", "#..#..", "#...#.", "#...#.", "#....#", "#....#"]),
+    ('X', ["#....#", "#....#", ".#..#.", ".#..#.", "..##..", "..##..", ".#..#.", ".#..#.", "#....#", "#....#"]),
+    ('Z', ["######", ".....#", ".....#", "....#.", "...#..", "..#...", ".#....", "#.....", "#.....", "######"]),
+];
+
+type Pixels = Vec<Vec<bool>>;
+
+// Reads the letters drawn with `#` (or `█`) over a background of any other
+// character, e.g. `.` or spaces
+pub fn ocr(art: &str) -> Result<String> {
+    read(
+        art.lines()
+            .map(|line| line.chars().map(|c| c == '#' || c == '█').collect())
+            .collect(),
+    )
+}
+
+// Reads the letters drawn by the cells of a grid for which `lit` holds
+pub fn ocr_grid<T>(grid: &Grid<T>, lit: impl Fn(&T) -> bool) -> Result<String> {
+    read(
+        (0..grid.rows())
+            .map(|row| {
+                (0..grid.cols())
+                    .map(|col| {
+                        grid.get(Point(col as isize, row as isize))
+                            .is_some_and(&lit)
+                    })
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+fn read(mut pixels: Pixels) -> Result<String> {
+    let Some(top) = pixels.iter().position(|row| row.contains(&true)) else {
+        return Err(Error::parse(1, 1, "no letter to read"));
+    };
+    let bottom = pixels
+        .iter()
+        .rposition(|row| row.contains(&true))
+        .unwrap_or(top);
+    pixels.truncate(bottom + 1);
+    pixels.drain(..top);
+
+    let letters = match pixels.len() {
+        6 => font(&SMALL),
+        10 => font(&LARGE),
+        height => {
+            return Err(Error::parse(
+                top + 1,
+                1,
+                format!("letters are 6 or 10 pixels high, not {}", height),
+            ))
+        }
+    };
+    glyphs(&pixels)
+        .into_iter()
+        .map(|(col, glyph)| {
+            letters
+                .iter()
+                .find(|(_, letter)| *letter == glyph)
+                .map(|(c, _)| *c)
+                .ok_or_else(|| Error::parse(top + 1, col + 1, "unknown letter"))
+        })
+        .collect()
+}
+
+fn font<const H: usize>(letters: &[(char, [&str; H])]) -> Vec<(char, Pixels)> {
+    letters
+        .iter()
+        .map(|(c, rows)| {
+            let pixels = rows
+                .iter()
+                .map(|row| row.chars().map(|c| c == '#').collect())
+                .collect::<Pixels>();
+            (*c, glyphs(&pixels).swap_remove(0).1)
+        })
+        .collect()
+}
+
+// Splits the pixels on blank columns, along with the column of each glyph
+fn glyphs(pixels: &Pixels) -> Vec<(usize, Pixels)> {
+    let width = pixels.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |row: &Vec<bool>, col: usize| row.get(col).copied().unwrap_or(false);
+    let blank = |col: usize| !pixels.iter().any(|row| lit(row, col));
+
+    let mut glyphs = Vec::new();
+    let mut col = 0;
+    while col < width {
+        if blank(col) {
+            col += 1;
+            continue;
+        }
+        let start = col;
+        while col < width && !blank(col) {
+            col += 1;
+        }
+        let glyph = pixels
+            .iter()
+            .map(|row| (start..col).map(|col| lit(row, col)).collect())
+            .collect();
+        glyphs.push((start, glyph));
+    }
+    glyphs
+}
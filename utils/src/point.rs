@@ -1,7 +1,4 @@
-use {
-    super::*,
-    std::cmp::{self, max},
-};
+use {super::*, std::cmp::max};
 
 #[derive(PartialEq, Eq, Clone, Copy, Debug, Hash, Default, PartialOrd, Ord)]
 ///  ^
@@ -43,46 +40,30 @@ impl Point {
 
     // Clockwise collection of Points surrounding a Point starting at from
     pub fn neighbours(&self, from: Direction) -> Vec<Self> {
-        let index = DIRECTIONS.iter().position(|&dir| dir == from).unwrap();
-        let (after, before) = DIRECTIONS.split_at(index + 1);
-        [before, after]
-            .concat()
-            .iter()
-            .map(|direction| self.neighbour(*direction))
-            .collect()
+        (0..8).map(|i| self.neighbour(from.turn(i))).collect()
     }
 
     pub fn orth_neighbour(&self, direction: Direction) -> Self {
         *self + Step(direction, 1)
     }
 
-    // Clockwise collection of Points surrounding a Point starting at from
+    // Clockwise collection of Points surrounding a Point starting at from, a
+    // quarter turn apart: the orthogonal ones, or the diagonal ones
     pub fn orth_neighbours(&self, from: Direction) -> Vec<Self> {
-        let directions: Vec<Direction> = match from {
-            Direction::East | Direction::North | Direction::South | Direction::West => DIRECTIONS
-                .into_iter()
-                .enumerate()
-                .filter_map(|(i, dir)| (i % 2 == 0).then_some(dir))
-                .collect(),
-            _ => DIRECTIONS
-                .into_iter()
-                .enumerate()
-                .filter_map(|(i, dir)| (i % 2 == 1).then_some(dir))
-                .collect(),
-        };
-        let index = directions.partition_point(|dir| *dir == from);
-        let (after, before) = directions.split_at(index - 1);
-        [before, after]
-            .concat()
-            .iter()
-            .map(|direction| self.orth_neighbour(*direction))
+        (0..4)
+            .map(|i| self.orth_neighbour(from.turn(2 * i)))
             .collect()
     }
 
+    // Whether the point lies in the rectangle from `min` to `max` included
+    pub fn is_within(&self, min: &Point, max: &Point) -> bool {
+        (min.0..=max.0).contains(&self.0) && (min.1..=max.1).contains(&self.1)
+    }
+
     pub fn follow(&self, point: &Point) -> Self {
         Self(
-            self.0 + cmp::max(cmp::min(point.0 - self.0, 1), -1),
-            self.1 + cmp::max(cmp::min(point.1 - self.1, 1), -1),
+            self.0 + (point.0 - self.0).clamp(-1, 1),
+            self.1 + (point.1 - self.1).clamp(-1, 1),
         )
     }
 }
@@ -0,0 +1,132 @@
+use {
+    super::*,
+    std::ops::{Add, AddAssign, Index, IndexMut, Sub, SubAssign},
+};
+
+// A point in `D` dimensions, for puzzles beyond the plane. `Point` remains the
+// one used on grids, and converts to and from `PointN<2>`.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Hash, PartialOrd, Ord)]
+pub struct PointN<const D: usize>(pub [isize; D]);
+
+pub type Point3 = PointN<3>;
+pub type Point4 = PointN<4>;
+
+impl<const D: usize> Default for PointN<D> {
+    fn default() -> Self {
+        Self([0; D])
+    }
+}
+
+impl<const D: usize> PointN<D> {
+    // Chebyshev or Chess distance, the largest difference along an axis
+    pub fn distance(&self, other: &Self) -> usize {
+        self.0
+            .iter()
+            .zip(other.0)
+            .map(|(a, b)| a.abs_diff(b))
+            .max()
+            .unwrap_or(0)
+    }
+
+    // L1 or Manhattan distance, the sum of the differences along each axis
+    pub fn orth_distance(&self, other: &Self) -> usize {
+        self.0.iter().zip(other.0).map(|(a, b)| a.abs_diff(b)).sum()
+    }
+
+    // The 2 * D orthogonal neighbours for `VonNeumann`, or the 3^D - 1 ones
+    // differing by at most 1 along each axis for `Moore`
+    pub fn neighbours(&self, neighbourhood: Neighbourhood) -> impl Iterator<Item = Self> {
+        let point = *self;
+        let offsets: Box<dyn Iterator<Item = [isize; D]>> = match neighbourhood {
+            Neighbourhood::VonNeumann => Box::new((0..D).flat_map(|axis| {
+                [-1, 1].map(|delta| {
+                    let mut offset = [0; D];
+                    offset[axis] = delta;
+                    offset
+                })
+            })),
+            Neighbourhood::Moore => Box::new((0..3usize.pow(D as u32)).map(|mut n| {
+                let mut offset = [0; D];
+                for delta in offset.iter_mut() {
+                    *delta = (n % 3) as isize - 1;
+                    n /= 3;
+                }
+                offset
+            })),
+        };
+        offsets
+            .filter(|offset| offset.iter().any(|delta| *delta != 0))
+            .map(move |offset| point + Self(offset))
+    }
+
+    // One step closer to `point` along each axis, as a knot follows another
+    pub fn follow(&self, point: &Self) -> Self {
+        let mut next = *self;
+        for (coord, target) in next.0.iter_mut().zip(point.0) {
+            *coord += (target - *coord).clamp(-1, 1);
+        }
+        next
+    }
+}
+
+impl<const D: usize> Add for PointN<D> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self::Output {
+        self += rhs;
+        self
+    }
+}
+
+impl<const D: usize> AddAssign for PointN<D> {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a += b);
+    }
+}
+
+impl<const D: usize> Sub for PointN<D> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self::Output {
+        self -= rhs;
+        self
+    }
+}
+
+impl<const D: usize> SubAssign for PointN<D> {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0.iter_mut().zip(rhs.0).for_each(|(a, b)| *a -= b);
+    }
+}
+
+impl<const D: usize> Index<usize> for PointN<D> {
+    type Output = isize;
+
+    fn index(&self, axis: usize) -> &isize {
+        &self.0[axis]
+    }
+}
+
+impl<const D: usize> IndexMut<usize> for PointN<D> {
+    fn index_mut(&mut self, axis: usize) -> &mut isize {
+        &mut self.0[axis]
+    }
+}
+
+impl<const D: usize> From<[isize; D]> for PointN<D> {
+    fn from(coords: [isize; D]) -> Self {
+        Self(coords)
+    }
+}
+
+impl From<Point> for PointN<2> {
+    fn from(Point(x, y): Point) -> Self {
+        Self([x, y])
+    }
+}
+
+impl From<PointN<2>> for Point {
+    fn from(PointN([x, y]): PointN<2>) -> Self {
+        Self(x, y)
+    }
+}
@@ -1,31 +1,19 @@
-pub trait Puzzle<A = usize, B = usize> {
-    const FILE: &'static str = "../input";
+use super::{Answer, Input, Result};
 
-    fn from_string(s: String) -> Self
+// Parts may be answered with any type convertible to an `Answer`
+pub trait Puzzle<A: Into<Answer> = usize, B: Into<Answer> = usize> {
+    fn from_string(s: String) -> Result<Self>
     where
-        Self: std::str::FromStr,
-        <Self as std::str::FromStr>::Err: std::fmt::Debug,
-    {
-        s.parse::<Self>().unwrap()
-    }
+        Self: Sized;
 
-    fn from_file() -> Self
+    fn from_input(input: &Input) -> Result<Self>
     where
-        Self: std::str::FromStr,
-        <Self as std::str::FromStr>::Err: std::fmt::Debug,
         Self: Sized,
     {
-        let data = {
-            let mut file = std::fs::File::open(Self::FILE).unwrap();
-            let mut data = String::new();
-            let _ = std::io::Read::read_to_string(&mut file, &mut data).unwrap();
-            data
-        };
-
-        Self::from_string(data)
+        Self::from_string(input.read()?)
     }
 
-    fn solve1(&self) -> A;
+    fn solve1(&self) -> Result<A>;
 
-    fn solve2(&self) -> B;
+    fn solve2(&self) -> Result<B>;
 }
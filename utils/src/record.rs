@@ -0,0 +1,142 @@
+use {
+    super::*,
+    std::{
+        io::{self, Write},
+        time::Duration,
+    },
+};
+
+// Collects a frame per step of a simulation, e.g. from a callback, to be
+// played back `delay` apart
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Recorder<F> {
+    frames: Vec<F>,
+    delay: Duration,
+}
+
+impl<F> Recorder<F> {
+    pub fn new(delay: Duration) -> Self {
+        Self {
+            frames: Vec::new(),
+            delay,
+        }
+    }
+
+    pub fn record(&mut self, frame: F) {
+        self.frames.push(frame);
+    }
+
+    pub fn frames(&self) -> &[F] {
+        &self.frames
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+}
+
+impl Recorder<Image> {
+    // An animated GIF looping forever. Every frame is drawn within the same
+    // points, those covered by any frame, `background` where it has none.
+    // Frames share a palette unless they use more than 256 colours.
+    pub fn write_gif(&self, background: Rgb, writer: impl Write) -> io::Result<()> {
+        let invalid = |message| io::Error::new(io::ErrorKind::InvalidInput, message);
+        let size = |n: usize| u16::try_from(n).map_err(|_| invalid("frame too large for a GIF"));
+        let frames = match self.frames.iter().filter_map(Image::bounds).reduce(
+            |(min, max), (other_min, other_max)| {
+                (
+                    Point(min.0.min(other_min.0), min.1.min(other_min.1)),
+                    Point(max.0.max(other_max.0), max.1.max(other_max.1)),
+                )
+            },
+        ) {
+            Some((min, max)) => self
+                .frames
+                .iter()
+                .map(|image| image.reframe(min, max, background))
+                .collect(),
+            None => self.frames.clone(),
+        };
+        let (width, height) = frames
+            .iter()
+            .map(Image::size)
+            .fold((0, 0), |(w, h), (width, height)| {
+                (w.max(width), h.max(height))
+            });
+        let mut colours = Vec::new();
+        for colour in frames.iter().flat_map(Image::scaled) {
+            if !colours.contains(&colour) {
+                colours.push(colour);
+                if colours.len() > 256 {
+                    break;
+                }
+            }
+        }
+        let shared = colours.len() <= 256;
+        let palette = if shared {
+            colours
+                .iter()
+                .flat_map(|Rgb(r, g, b)| [*r, *g, *b])
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        let mut encoder = gif::Encoder::new(writer, size(width)?, size(height)?, &palette)
+            .map_err(io::Error::other)?;
+        encoder
+            .set_repeat(gif::Repeat::Infinite)
+            .map_err(io::Error::other)?;
+        // In hundredths of a second
+        let delay = u16::try_from(self.delay.as_millis() / 10)
+            .map_err(|_| invalid("delay too long for a GIF"))?;
+        for image in &frames {
+            let (width, height) = image.size();
+            let mut frame = if shared {
+                let pixels = image
+                    .scaled()
+                    .map(|colour| colours.iter().position(|c| *c == colour).unwrap() as u8)
+                    .collect::<Vec<_>>();
+                gif::Frame::from_indexed_pixels(size(width)?, size(height)?, pixels, None)
+            } else {
+                gif::Frame::from_rgb_speed(size(width)?, size(height)?, &image.rgb(), 10)
+            };
+            frame.delay = delay;
+            frame.dispose = gif::DisposalMethod::Background;
+            encoder.write_frame(&frame).map_err(io::Error::other)?;
+        }
+        Ok(())
+    }
+}
+
+impl Recorder<String> {
+    // An asciicast v2 recording, played with `asciinema play`, each frame
+    // clearing the terminal
+    pub fn write_asciicast(&self, mut writer: impl Write) -> io::Result<()> {
+        let width = self
+            .frames
+            .iter()
+            .flat_map(|frame| frame.lines())
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let height = self
+            .frames
+            .iter()
+            .map(|frame| frame.lines().count())
+            .max()
+            .unwrap_or(0);
+        let header = serde_json::json!({ "version": 2, "width": width, "height": height });
+        writeln!(writer, "{}", header)?;
+        for (i, frame) in self.frames.iter().enumerate() {
+            let time = (self.delay * i as u32).as_secs_f64();
+            let output = format!("\x1b[H\x1b[2J{}", frame.replace('\n', "\r\n"));
+            writeln!(writer, "{}", serde_json::json!([time, "o", output]))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use {
+    super::*,
+    std::collections::{BTreeSet, VecDeque},
+};
+
+// A set of orthogonally connected cells of a grid
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct Region(BTreeSet<Point>);
+
+impl Region {
+    pub fn points(&self) -> impl Iterator<Item = &Point> {
+        self.0.iter()
+    }
+
+    pub fn contains(&self, point: &Point) -> bool {
+        self.0.contains(point)
+    }
+
+    pub fn area(&self) -> usize {
+        self.0.len()
+    }
+
+    // Number of cell edges between the region and the outside
+    pub fn perimeter(&self) -> usize {
+        self.0
+            .iter()
+            .flat_map(|point| Neighbourhood::VonNeumann.points(*point))
+            .filter(|neighbour| !self.contains(neighbour))
+            .count()
+    }
+
+    // Number of straight fences needed to enclose the region, holes included,
+    // which is also its number of corners. Each cell has a corner between two
+    // orthogonal directions when both neighbours are outside (convex), or when
+    // both are inside but the diagonal one is not (concave).
+    pub fn sides(&self) -> usize {
+        self.0
+            .iter()
+            .map(|point| {
+                (0..DIRECTIONS.len())
+                    .step_by(2)
+                    .filter(|i| {
+                        let inside = |i: usize| self.contains(&point.neighbour(DIRECTIONS[i % 8]));
+                        let (first, diagonal, second) = (inside(*i), inside(i + 1), inside(i + 2));
+                        (!first && !second) || (first && second && !diagonal)
+                    })
+                    .count()
+            })
+            .sum()
+    }
+}
+
+impl FromIterator<Point> for Region {
+    fn from_iter<I: IntoIterator<Item = Point>>(points: I) -> Self {
+        Self(points.into_iter().collect())
+    }
+}
+
+// The regions of a grid, along with the label of each cell, i.e. the index of
+// its region
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Components {
+    pub labels: Grid<usize>,
+    pub regions: Vec<Region>,
+}
+
+impl<T> Grid<T> {
+    // Cells orthogonally reachable from `start` through cells for which
+    // `predicate` holds, `start` included
+    pub fn flood_fill(&self, start: Point, predicate: impl Fn(&T) -> bool) -> Region {
+        let mut region = BTreeSet::new();
+        if !self.get(start).is_some_and(&predicate) {
+            return Region(region);
+        }
+        let mut queue = VecDeque::from([start]);
+        region.insert(start);
+        while let Some(point) = queue.pop_front() {
+            for (neighbour, value) in self.neighbours(point, Neighbourhood::VonNeumann) {
+                if predicate(value) && region.insert(neighbour) {
+                    queue.push_back(neighbour);
+                }
+            }
+        }
+        Region(region)
+    }
+
+    // Splits the grid into regions of cells linked through neighbours for
+    // which `eq` holds, labelled in reading order. Every cell belongs to one
+    // region, alone if `eq` links it to no neighbour.
+    pub fn components(&self, eq: impl Fn(&T, &T) -> bool) -> Components {
+        let mut labels = vec![usize::MAX; self.len()];
+        let mut regions = Vec::new();
+        for start in self.points() {
+            if labels[self.index(start)] != usize::MAX {
+                continue;
+            }
+            let label = regions.len();
+            let mut region = BTreeSet::from([start]);
+            let mut queue = VecDeque::from([start]);
+            labels[self.index(start)] = label;
+            while let Some(point) = queue.pop_front() {
+                let value = &self[self.index(point)];
+                for (neighbour, other) in self.neighbours(point, Neighbourhood::VonNeumann) {
+                    let i = self.index(neighbour);
+                    if labels[i] == usize::MAX && eq(value, other) {
+                        labels[i] = label;
+                        region.insert(neighbour);
+                        queue.push_back(neighbour);
+                    }
+                }
+            }
+            regions.push(Region(region));
+        }
+        Components {
+            labels: Grid {
+                max: self.max(),
+                values: labels,
+                origin: self.origin,
+            },
+            regions,
+        }
+    }
+
+    // Whether a region is surrounded by other cells, without touching the edge
+    pub fn is_enclosed(&self, region: &Region) -> bool {
+        !region.points().any(|point| self.is_outer(*point))
+    }
+}
@@ -0,0 +1,194 @@
+use {
+    super::*,
+    std::io::{self, Write},
+};
+
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Self = Self(0, 0, 0);
+    pub const WHITE: Self = Self(255, 255, 255);
+    pub const RED: Self = Self(220, 50, 47);
+    pub const GREEN: Self = Self(133, 153, 0);
+    pub const BLUE: Self = Self(38, 139, 210);
+
+    // The colour a fraction `t` of the way from `self` to `other`
+    pub fn mix(self, other: Self, t: f64) -> Self {
+        let t = t.clamp(0., 1.);
+        let mix = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Self(
+            mix(self.0, other.0),
+            mix(self.1, other.1),
+            mix(self.2, other.2),
+        )
+    }
+
+    // A colour map for heatmaps, from dark blue for `min` to yellow for `max`
+    pub fn heat(value: f64, min: f64, max: f64) -> Self {
+        let t = if max > min {
+            (value - min) / (max - min)
+        } else {
+            0.
+        };
+        let (dark, mid, light) = (Self(30, 20, 80), Self(200, 60, 90), Self(250, 230, 80));
+        if t < 0.5 {
+            dark.mix(mid, t * 2.)
+        } else {
+            mid.mix(light, t * 2. - 1.)
+        }
+    }
+
+    fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.0, self.1, self.2)
+    }
+}
+
+// The colours of a rectangle of points, the first row on top unless `y_up` is
+// set, ready to be exported with `scale` pixels per point
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Image {
+    min: Point,
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+    scale: usize,
+    y_up: bool,
+}
+
+impl Image {
+    pub fn new(min: Point, max: Point, colour: impl Fn(Point) -> Rgb) -> Self {
+        let width = (max.0 - min.0 + 1).max(0) as usize;
+        let height = (max.1 - min.1 + 1).max(0) as usize;
+        let pixels = (min.1..=max.1)
+            .flat_map(|y| (min.0..=max.0).map(move |x| Point(x, y)))
+            .map(colour)
+            .collect();
+        Self {
+            min,
+            width,
+            height,
+            pixels,
+            scale: 1,
+            y_up: false,
+        }
+    }
+
+    pub fn scale(mut self, scale: usize) -> Self {
+        self.scale = scale.max(1);
+        self
+    }
+
+    // Draws the largest `y` on top, as on a plane
+    pub fn y_up(mut self) -> Self {
+        self.y_up = true;
+        self
+    }
+
+    // The points coloured, unless there is none
+    pub fn bounds(&self) -> Option<(Point, Point)> {
+        (self.width > 0 && self.height > 0).then(|| {
+            let size = Point(self.width as isize - 1, self.height as isize - 1);
+            (self.min, self.min + size)
+        })
+    }
+
+    // The same points within another rectangle, `background` where there was
+    // none before
+    pub fn reframe(&self, min: Point, max: Point, background: Rgb) -> Self {
+        let image = Self::new(min, max, |point| {
+            let Point(x, y) = point - self.min;
+            if (0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y) {
+                self.pixels[y as usize * self.width + x as usize]
+            } else {
+                background
+            }
+        });
+        Self {
+            scale: self.scale,
+            y_up: self.y_up,
+            ..image
+        }
+    }
+
+    // Size in pixels once scaled
+    pub fn size(&self) -> (usize, usize) {
+        (self.width * self.scale, self.height * self.scale)
+    }
+
+    // The points of the `i`-th row from the top
+    fn row(&self, i: usize) -> &[Rgb] {
+        let i = if self.y_up { self.height - 1 - i } else { i };
+        &self.pixels[i * self.width..(i + 1) * self.width]
+    }
+
+    // Every pixel once scaled, row by row
+    pub fn scaled(&self) -> impl Iterator<Item = Rgb> + '_ {
+        let (width, height) = self.size();
+        (0..height).flat_map(move |y| {
+            let row = self.row(y / self.scale);
+            (0..width).map(move |x| row[x / self.scale])
+        })
+    }
+
+    // Red, green and blue bytes of every pixel once scaled
+    pub fn rgb(&self) -> Vec<u8> {
+        self.scaled().flat_map(|Rgb(r, g, b)| [r, g, b]).collect()
+    }
+
+    // A square per point
+    pub fn to_svg(&self) -> String {
+        let (width, height) = self.size();
+        let mut svg = format!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" shape-rendering="crispEdges">"#,
+            width, height
+        );
+        svg += "\n";
+        for y in 0..self.height {
+            for (x, colour) in self.row(y).iter().enumerate() {
+                svg += &format!(
+                    r#"<rect x="{}" y="{}" width="{}" height="{}" fill="{}"/>"#,
+                    x * self.scale,
+                    y * self.scale,
+                    self.scale,
+                    self.scale,
+                    colour.hex()
+                );
+                svg += "\n";
+            }
+        }
+        svg + "</svg>\n"
+    }
+
+    // Binary Portable PixMap (P6)
+    pub fn write_ppm(&self, mut writer: impl Write) -> io::Result<()> {
+        let (width, height) = self.size();
+        write!(writer, "P6\n{} {}\n255\n", width, height)?;
+        writer.write_all(&self.rgb())
+    }
+
+    pub fn write_png(&self, writer: impl Write) -> io::Result<()> {
+        let (width, height) = self.size();
+        let mut encoder = png::Encoder::new(writer, width as u32, height as u32);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(&self.rgb())?;
+        Ok(())
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn image(&self, colour: impl Fn(&T) -> Rgb) -> Image {
+        Image::new(self.min(), self.max(), |point| {
+            self.get(point).map_or(Rgb::BLACK, &colour)
+        })
+    }
+}
+
+impl<T, S: Storage<T>> SparseGrid<T, S> {
+    // Colours the bounding box of the points, `None` standing for the missing ones
+    pub fn image(&self, colour: impl Fn(Option<&T>) -> Rgb) -> Image {
+        let (min, max) = self.extent();
+        Image::new(min, max, |point| colour(self.get(&point)))
+    }
+}
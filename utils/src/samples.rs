@@ -0,0 +1,71 @@
+use {
+    super::{Answers, Input, Result, Solution, Status},
+    std::path::PathBuf,
+};
+
+// Examples live next to the day's input, as numbered files along with their
+// expected answers keyed by number:
+//
+// samples/1.txt
+// samples/2.txt
+// samples/answers.toml
+pub fn samples_dir(solution: &Solution) -> PathBuf {
+    PathBuf::from(solution.manifest_dir)
+        .join("..")
+        .join("samples")
+}
+
+pub fn samples(solution: &Solution) -> Result<Vec<(u8, Input)>> {
+    let dir = samples_dir(solution);
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let mut samples = std::fs::read_dir(dir)?
+        .map(|entry| entry.map(|entry| entry.path()))
+        .filter_map(|path| match path {
+            Ok(path) => {
+                let number = path.file_stem()?.to_str()?.parse::<u8>().ok();
+                let is_txt = path.extension().is_some_and(|ext| ext == "txt");
+                number
+                    .filter(|_| is_txt)
+                    .map(|number| Ok((number, Input::Path(path))))
+            }
+            Err(err) => Some(Err(err.into())),
+        })
+        .collect::<Result<Vec<_>>>()?;
+    samples.sort_by_key(|(number, _)| *number);
+    Ok(samples)
+}
+
+// Solves every sample and compares the parts whose answer is known, returning
+// a line per failure
+pub fn check_samples(solution: &Solution) -> Result<Vec<String>> {
+    let answers = Answers::load(samples_dir(solution).join("answers.toml"))?;
+    let mut failures = Vec::new();
+    for (number, input) in samples(solution)? {
+        let run = (solution.solve)(input.read()?)?;
+        for (i, part) in run.parts.into_iter().enumerate() {
+            let part_number = i as u8 + 1;
+            if answers.get(number, part_number).is_none() {
+                continue;
+            }
+            let failure = match part {
+                Ok(answer) => match answers.check(number, part_number, &answer) {
+                    status @ Status::Wrong(_) => Some(format!("{} is {}", answer, status)),
+                    _ => None,
+                },
+                Err(err) => Some(err.to_string()),
+            };
+            if let Some(failure) = failure {
+                failures.push(format!(
+                    "{} sample {} part {}: {}",
+                    solution.name(),
+                    number,
+                    part_number,
+                    failure
+                ));
+            }
+        }
+    }
+    Ok(failures)
+}
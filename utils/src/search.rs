@@ -0,0 +1,275 @@
+use {
+    super::*,
+    std::{
+        cmp::Reverse,
+        collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+        hash::Hash,
+        iter,
+    },
+};
+
+// The cheapest way found to a goal: its total cost and the states from the
+// start to the goal, both included
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Path<S> {
+    pub cost: usize,
+    pub states: Vec<S>,
+}
+
+// The states met so far, each with the index of the one it was reached from
+struct Visited<S> {
+    states: Vec<(S, Option<usize>, usize)>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash> Visited<S> {
+    fn new() -> Self {
+        Self {
+            states: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    // The index of the state, unless it was already reached at a lower cost
+    fn visit(&mut self, state: S, parent: Option<usize>, cost: usize) -> Option<usize> {
+        match self.index.entry(state.clone()) {
+            Entry::Occupied(entry) => {
+                let i = *entry.get();
+                (cost < self.states[i].2).then(|| {
+                    self.states[i] = (state, parent, cost);
+                    i
+                })
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(self.states.len());
+                self.states.push((state, parent, cost));
+                Some(self.states.len() - 1)
+            }
+        }
+    }
+
+    fn costs(self) -> HashMap<S, usize> {
+        self.states
+            .into_iter()
+            .map(|(state, _, cost)| (state, cost))
+            .collect()
+    }
+
+    fn path(&self, mut i: usize) -> Path<S> {
+        let cost = self.states[i].2;
+        let mut states = vec![self.states[i].0.clone()];
+        while let Some(parent) = self.states[i].1 {
+            states.push(self.states[parent].0.clone());
+            i = parent;
+        }
+        states.reverse();
+        Path { cost, states }
+    }
+}
+
+// Visits the states in order of cost from the closest start, every move
+// costing 1, until a goal is met
+fn breadth_first<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+) -> (Visited<S>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let mut visited = Visited::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        queue.extend(visited.visit(start, None, 0));
+    }
+    while let Some(i) = queue.pop_front() {
+        let (state, _, cost) = &visited.states[i];
+        if goal(state) {
+            return (visited, Some(i));
+        }
+        let cost = cost + 1;
+        for next in successors(state) {
+            queue.extend(visited.visit(next, Some(i), cost));
+        }
+    }
+    (visited, None)
+}
+
+// Visits the states in order of cost from the closest start plus estimated
+// cost left, the deepest first on ties, until a goal is met
+fn best_first<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    mut successors: impl FnMut(&S) -> I,
+    mut goal: impl FnMut(&S) -> bool,
+    mut heuristic: impl FnMut(&S) -> usize,
+) -> (Visited<S>, Option<usize>)
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let mut visited = Visited::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(i) = visited.visit(start, None, 0) {
+            heap.push(Reverse((estimate, Reverse(0), i)));
+        }
+    }
+    while let Some(Reverse((_, Reverse(cost), i))) = heap.pop() {
+        let state = &visited.states[i].0;
+        if cost > visited.states[i].2 {
+            // Already reached at a lower cost
+            continue;
+        }
+        if goal(state) {
+            return (visited, Some(i));
+        }
+        for (next, step) in successors(state) {
+            let estimate = heuristic(&next);
+            if let Some(j) = visited.visit(next, Some(i), cost + step) {
+                heap.push(Reverse((cost + step + estimate, Reverse(cost + step), j)));
+            }
+        }
+    }
+    (visited, None)
+}
+
+// Breadth-first search, where every move costs 1
+pub fn bfs<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    bfs_from(iter::once(start), successors, goal)
+}
+
+// Breadth-first search from the closest of many starts
+pub fn bfs_from<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let (visited, goal) = breadth_first(starts, successors, goal);
+    goal.map(|i| visited.path(i))
+}
+
+// The number of moves to every reachable state from the closest start
+pub fn bfs_distances<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    breadth_first(starts, successors, |_| false).0.costs()
+}
+
+// Dijkstra's algorithm, successors coming with the cost of the move to them
+pub fn dijkstra<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    dijkstra_from(iter::once(start), successors, goal)
+}
+
+// Dijkstra's algorithm from the cheapest of many starts
+pub fn dijkstra_from<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar_from(starts, successors, goal, |_| 0)
+}
+
+// The lowest cost of every reachable state from any start
+pub fn dijkstra_distances<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    best_first(starts, successors, |_| false, |_| 0).0.costs()
+}
+
+// A* search, guided by a heuristic that must never overestimate the cost left
+// to reach a goal, e.g. the Manhattan distance on a grid
+pub fn astar<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+    heuristic: impl FnMut(&S) -> usize,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    astar_from(iter::once(start), successors, goal, heuristic)
+}
+
+pub fn astar_from<S, I>(
+    starts: impl IntoIterator<Item = S>,
+    successors: impl FnMut(&S) -> I,
+    goal: impl FnMut(&S) -> bool,
+    heuristic: impl FnMut(&S) -> usize,
+) -> Option<Path<S>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    let (visited, goal) = best_first(starts, successors, goal, heuristic);
+    goal.map(|i| visited.path(i))
+}
+
+impl<T> Grid<T> {
+    // The number of moves from the closest start to each cell, if reachable
+    pub fn distances<I>(
+        &self,
+        starts: impl IntoIterator<Item = Point>,
+        successors: impl FnMut(&Point) -> I,
+    ) -> Grid<Option<usize>>
+    where
+        I: IntoIterator<Item = Point>,
+    {
+        self.distance_map(bfs_distances(starts, successors))
+    }
+
+    // The lowest cost from any start to each cell, if reachable
+    pub fn weighted_distances<I>(
+        &self,
+        starts: impl IntoIterator<Item = Point>,
+        successors: impl FnMut(&Point) -> I,
+    ) -> Grid<Option<usize>>
+    where
+        I: IntoIterator<Item = (Point, usize)>,
+    {
+        self.distance_map(dijkstra_distances(starts, successors))
+    }
+
+    fn distance_map(&self, distances: HashMap<Point, usize>) -> Grid<Option<usize>> {
+        Grid::from_fn(self.cols(), self.rows(), |point| {
+            distances.get(&point).copied()
+        })
+        .with_origin(self.origin)
+    }
+}
@@ -0,0 +1,79 @@
+use super::{Answer, Result, Timings};
+
+// A puzzle erased from its `Puzzle` implementation, so that days answering
+// with different types can be stored side by side in the runner's registry.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    // Where the day's crate lives, to locate its default input
+    pub manifest_dir: &'static str,
+    pub solve: fn(String) -> Result<Run>,
+}
+
+// The answers of both parts, and how long it took to parse then solve them
+#[derive(Debug)]
+pub struct Run {
+    pub parts: [Result<Answer>; 2],
+    pub timings: Timings,
+}
+
+impl Solution {
+    pub fn name(&self) -> String {
+        format!("{} Day {}", self.year, self.day)
+    }
+}
+
+impl std::fmt::Debug for Solution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Solution")
+            .field("year", &self.year)
+            .field("day", &self.day)
+            .finish()
+    }
+}
+
+// Exposes a `Puzzle` implementation as `pub const SOLUTION: Solution`, along
+// with a test checking it against the day's `samples/`.
+#[macro_export]
+macro_rules! solution {
+    ($year:literal, $day:literal, $puzzle:ty) => {
+        pub const SOLUTION: $crate::Solution = $crate::Solution {
+            year: $year,
+            day: $day,
+            manifest_dir: env!("CARGO_MANIFEST_DIR"),
+            solve: |input| {
+                let start = std::time::Instant::now();
+                let puzzle = <$puzzle>::from_string(input)?;
+                let parse = start.elapsed();
+
+                let start = std::time::Instant::now();
+                let part1 = puzzle.solve1();
+                let part1_time = start.elapsed();
+
+                let start = std::time::Instant::now();
+                let part2 = puzzle.solve2();
+                let part2_time = start.elapsed();
+
+                Ok($crate::Run {
+                    parts: [
+                        part1.map($crate::Answer::from),
+                        part2.map($crate::Answer::from),
+                    ],
+                    timings: $crate::Timings {
+                        parse,
+                        part1: part1_time,
+                        part2: part2_time,
+                    },
+                })
+            },
+        };
+
+        #[cfg(test)]
+        #[test]
+        fn samples() {
+            let failures = $crate::check_samples(&SOLUTION).unwrap();
+            assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+        }
+    };
+}
@@ -23,6 +23,64 @@ pub enum Direction {
     NorthWest,
 }
 
+impl Direction {
+    // Rotates by `by` eighths of a turn, clockwise when positive
+    pub fn turn(self, by: i8) -> Self {
+        DIRECTIONS[(self as i8 + by).rem_euclid(8) as usize]
+    }
+
+    // A quarter turn clockwise
+    pub fn turn_right(self) -> Self {
+        self.turn(2)
+    }
+
+    // A quarter turn counterclockwise
+    pub fn turn_left(self) -> Self {
+        self.turn(-2)
+    }
+
+    pub fn opposite(self) -> Self {
+        self.turn(4)
+    }
+
+    pub fn is_orthogonal(self) -> bool {
+        matches!(self, Self::North | Self::East | Self::South | Self::West)
+    }
+
+    // The point one step away from the origin
+    pub fn unit(self) -> Point {
+        Point::default() + Step(self, 1)
+    }
+}
+
+// Up, down, left and right as `U`, `D`, `L` and `R`, compass points as `N`,
+// `E`, `S` and `W`, or arrows as `^`, `v`, `<` and `>`
+impl TryFrom<char> for Direction {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | 'N' | '^' => Ok(Self::North),
+            'R' | 'E' | '>' => Ok(Self::East),
+            'D' | 'S' | 'v' => Ok(Self::South),
+            'L' | 'W' | '<' => Ok(Self::West),
+            _ => Err(format!("unknown direction {:?}", c)),
+        }
+    }
+}
+
+impl std::str::FromStr for Direction {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => c.try_into(),
+            _ => Err(format!("unknown direction {:?}", s)),
+        }
+    }
+}
+
 #[derive(PartialEq, Eq, Clone, Copy, Debug, PartialOrd, Ord)]
 pub struct Step(pub Direction, pub isize);
 
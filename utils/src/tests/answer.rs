@@ -0,0 +1,40 @@
+use crate::*;
+
+#[test]
+fn test_from() {
+    assert_eq!(Answer::from(42usize), Answer::Unsigned(42));
+    assert_eq!(Answer::from(-1isize), Answer::Signed(-1));
+    assert_eq!(Answer::from("CMZ"), Answer::Text("CMZ".into()));
+    assert!(Answer::from("\n#..#\n####\n").is_art());
+    assert!(!Answer::from("CMZ\n").is_art());
+}
+
+#[test]
+fn test_eq() {
+    assert_eq!(Answer::Unsigned(280), Answer::Signed(280));
+    assert_ne!(Answer::Unsigned(1), Answer::Signed(-1));
+    assert_ne!(Answer::Unsigned(1), Answer::Text("1".into()));
+    assert_eq!(Answer::from("#..#\n####"), Answer::from("\n#..#\n####\n"));
+}
+
+#[test]
+fn test_serde() {
+    let answers = [
+        Answer::from(1797usize),
+        Answer::from(-3isize),
+        Answer::from("CMZ"),
+        Answer::from("#..#\n####"),
+    ];
+    let json = serde_json::to_string(&answers).unwrap();
+    assert_eq!(json, r##"[1797,-3,"CMZ","#..#\n####"]"##);
+    assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    assert!(serde_json::from_str::<Answer>(r##""#..#\n####""##)
+        .unwrap()
+        .is_art());
+}
+
+#[test]
+fn test_display() {
+    assert_eq!(Answer::from(-3isize).to_string(), "-3");
+    assert_eq!(Answer::from("\n#..#\n####\n").to_string(), "#..#\n####");
+}
@@ -0,0 +1,55 @@
+use crate::*;
+
+const SAMPLE: &str = r#"
+[5]
+part1 = "CMZ"
+part2 = "MCD"
+
+[10]
+part1 = 13140
+part2 = """
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+"""
+
+[12]
+part1 = 31
+"#;
+
+#[test]
+fn test_parser() {
+    let answers = SAMPLE.parse::<Answers>().unwrap();
+    assert_eq!(answers.get(5, 1), Some(&"CMZ".into()));
+    assert_eq!(answers.get(10, 1), Some(&13140usize.into()));
+    assert_eq!(answers.get(12, 2), None);
+    assert_eq!(answers.get(1, 1), None);
+
+    assert!(matches!(
+        "[1]\npart1 = [1, 2]".parse::<Answers>(),
+        Err(Error::Parse { line: 2, .. })
+    ));
+    assert!(matches!(
+        "[first]\npart1 = 1".parse::<Answers>(),
+        Err(Error::Parse { line: 1, .. })
+    ));
+}
+
+#[test]
+fn test_check() {
+    let answers = SAMPLE.parse::<Answers>().unwrap();
+    assert_eq!(answers.check(5, 1, &"CMZ".into()), Status::Correct);
+    assert_eq!(
+        answers.check(5, 2, &"CMZ".into()),
+        Status::Wrong("MCD".into())
+    );
+    assert_eq!(answers.check(10, 1, &13140usize.into()), Status::Correct);
+    assert_eq!(
+        answers.check(
+            10,
+            2,
+            &"\n##..##..##..##..##..##..##..##..##..##..\n###...###...###...###...###...###...###.\n".into()
+        ),
+        Status::Correct
+    );
+    assert_eq!(answers.check(12, 2, &29usize.into()), Status::Unknown);
+}
@@ -0,0 +1,48 @@
+use crate::*;
+
+fn grid() -> Grid<bool> {
+    Grid::parse("#..\n.#.\n..#", |c| Ok::<_, String>(c == '#')).unwrap()
+}
+
+fn cell(lit: &bool) -> char {
+    if *lit {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+#[test]
+fn test_grid() {
+    assert_eq!(grid().display(cell).to_string(), "#..\n.#.\n..#\n");
+    assert_eq!(grid().display(cell).y_up().to_string(), "..#\n.#.\n#..\n");
+}
+
+#[test]
+fn test_overlay() {
+    let path = [Point(0, 1), Point(0, 2), Point(1, 2)];
+    assert_eq!(
+        grid()
+            .display(cell)
+            .overlay(path, 'o')
+            .overlay([Point(1, 2)], 'E')
+            .to_string(),
+        "#..\no#.\noE#\n"
+    );
+}
+
+#[test]
+fn test_axes() {
+    let mut sparse = SparseGrid::new();
+    sparse.insert(Point(-1, 0), 'a');
+    sparse.insert(Point(10, 2), 'b');
+    let render = sparse.display(|c| c.copied().unwrap_or('.'));
+    assert_eq!(
+        render.axes().y_up().to_string(),
+        "  -          1\n  \
+         101234567890\n\
+         2 ...........b\n\
+         1 ............\n\
+         0 a...........\n"
+    );
+}
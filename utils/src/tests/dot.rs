@@ -0,0 +1,45 @@
+use crate::*;
+
+fn dot() -> Dot {
+    let grid = Grid::from(vec![vec!['a', 'b'], vec!['"', 'd']]);
+    grid.dot(
+        |c| c.to_string(),
+        |point| {
+            [Point(point.0 + 1, point.1)]
+                .into_iter()
+                .filter(|next| next.0 < 2)
+        },
+    )
+}
+
+#[test]
+fn test_dot() {
+    assert_eq!(
+        dot().to_string(),
+        "digraph {\n    \
+         node [shape = square, fixedsize = true, width = 0.5]\n    \
+         \"0,0\" [label = \"a\", pos = \"0,0!\"]\n    \
+         \"1,0\" [label = \"b\", pos = \"1,0!\"]\n    \
+         \"0,1\" [label = \"\\\"\", pos = \"0,-1!\"]\n    \
+         \"1,1\" [label = \"d\", pos = \"1,-1!\"]\n    \
+         \"0,0\" -> \"1,0\"\n    \
+         \"0,1\" -> \"1,1\"\n\
+         }\n"
+    );
+}
+
+#[test]
+fn test_highlights() {
+    let dot = dot()
+        .visited([Point(0, 0), Point(0, 1)], "grey")
+        .path(&[Point(0, 1), Point(1, 1)], "red")
+        .to_string();
+    assert!(dot
+        .contains("\"0,0\" [label = \"a\", pos = \"0,0!\", style = filled, fillcolor = \"grey\"]"));
+    assert!(dot.contains(
+        "\"0,1\" [label = \"\\\"\", pos = \"0,-1!\", style = filled, fillcolor = \"red\"]"
+    ));
+    assert!(dot.contains("\"1,0\" [label = \"b\", pos = \"1,0!\"]"));
+    assert!(dot.contains("\"0,1\" -> \"1,1\" [color = \"red\", penwidth = 3]"));
+    assert!(dot.contains("\"0,0\" -> \"1,0\"\n"));
+}
@@ -0,0 +1,178 @@
+use crate::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Open,
+    Wall,
+}
+
+impl TryFrom<char> for Cell {
+    type Error = String;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '.' => Ok(Self::Open),
+            '#' => Ok(Self::Wall),
+            _ => Err(format!("unknown cell {:?}", c)),
+        }
+    }
+}
+
+#[test]
+fn test_parse() {
+    let grid = Grid::parse("\n123\n456\n\n", |c| c.to_digit(10).ok_or("not a digit")).unwrap();
+    assert_eq!(grid.cols(), 3);
+    assert_eq!(grid.rows(), 2);
+    assert_eq!(grid.get(Point(2, 1)), Some(&6));
+    assert_eq!(grid.get(Point(0, 2)), None);
+
+    let grid = "#.\n.#".parse::<Grid<Cell>>().unwrap();
+    assert_eq!(grid.get(Point(1, 0)), Some(&Cell::Open));
+    assert_eq!(grid.get(Point(1, 1)), Some(&Cell::Wall));
+}
+
+#[test]
+fn test_parse_errors() {
+    assert!(matches!(
+        "\n#.\n.x".parse::<Grid<Cell>>(),
+        Err(Error::Parse {
+            line: 3,
+            column: 2,
+            ..
+        })
+    ));
+    assert!(matches!(
+        "#.\n#.#".parse::<Grid<Cell>>(),
+        Err(Error::Parse {
+            line: 2,
+            column: 3,
+            ..
+        })
+    ));
+    assert!(matches!(
+        "#.\n#".parse::<Grid<Cell>>(),
+        Err(Error::Parse {
+            line: 2,
+            column: 2,
+            ..
+        })
+    ));
+    assert!(matches!(
+        "#.\n\n#.".parse::<Grid<Cell>>(),
+        Err(Error::Parse {
+            line: 2,
+            column: 1,
+            ..
+        })
+    ));
+    assert!("\n  \n".parse::<Grid<Cell>>().is_err());
+}
+
+#[test]
+fn test_rows_and_cols() {
+    let grid = Grid::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    assert_eq!(grid.get_row(1), Some(&[4, 5, 6][..]));
+    assert_eq!(grid.get_row(2), None);
+    assert_eq!(grid.get_col(2).unwrap().collect::<Vec<_>>(), vec![&3, &6]);
+    assert_eq!(
+        grid.get_col(1).unwrap().rev().collect::<Vec<_>>(),
+        vec![&5, &2]
+    );
+    assert!(grid.get_col(3).is_none());
+    assert_eq!(grid.iter_rows().count(), 2);
+    assert_eq!(
+        grid.iter_cols()
+            .map(|col| col.sum::<i32>())
+            .collect::<Vec<_>>(),
+        vec![5, 7, 9]
+    );
+}
+
+#[test]
+fn test_ray() {
+    let grid = Grid::from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    let values = |ray: Ray<'_, i32>| ray.map(|(_, value)| *value).collect::<Vec<_>>();
+    assert_eq!(values(grid.ray(Point(0, 0), Direction::East)), vec![2, 3]);
+    assert_eq!(
+        values(grid.ray(Point(0, 0), Direction::NorthEast)),
+        vec![5, 9]
+    );
+    assert_eq!(values(grid.ray(Point(1, 2), Direction::South)), vec![5, 2]);
+    assert!(values(grid.ray(Point(2, 1), Direction::East)).is_empty());
+    assert_eq!(
+        grid.ray(Point(2, 0), Direction::NorthWest)
+            .collect::<Vec<_>>(),
+        vec![(Point(1, 1), &5), (Point(0, 2), &7)]
+    );
+}
+
+#[test]
+fn test_lines() {
+    let grid = Grid::from(vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    let lines = |direction| {
+        grid.lines(direction)
+            .map(|line| line.map(|(_, value)| *value).collect::<Vec<_>>())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(lines(Direction::East), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    assert_eq!(
+        lines(Direction::South),
+        vec![vec![4, 1], vec![5, 2], vec![6, 3]]
+    );
+    assert_eq!(
+        lines(Direction::NorthEast),
+        vec![vec![1, 5], vec![2, 6], vec![3], vec![4]]
+    );
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = Grid::from(vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    let values = |neighbours: Vec<(Point, &i32)>| {
+        neighbours
+            .into_iter()
+            .map(|(_, value)| *value)
+            .collect::<Vec<_>>()
+    };
+    let collect = |point, neighbourhood| grid.neighbours(point, neighbourhood).collect();
+    assert_eq!(
+        values(collect(Point(1, 1), Neighbourhood::VonNeumann)),
+        vec![8, 6, 2, 4]
+    );
+    assert_eq!(
+        values(collect(Point(1, 1), Neighbourhood::Moore)),
+        vec![8, 9, 6, 3, 2, 1, 4, 7]
+    );
+    assert_eq!(
+        values(collect(Point(0, 0), Neighbourhood::VonNeumann)),
+        vec![4, 2]
+    );
+    assert_eq!(
+        grid.neighbours(Point(0, 0), Neighbourhood::Moore)
+            .collect::<Vec<_>>(),
+        vec![(Point(0, 1), &4), (Point(1, 1), &5), (Point(1, 0), &2)]
+    );
+
+    let wrapping = grid
+        .neighbours_wrapping(Point(0, 0), Neighbourhood::VonNeumann)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        wrapping,
+        vec![
+            (Point(0, 1), &4),
+            (Point(1, 0), &2),
+            (Point(0, 2), &7),
+            (Point(2, 0), &3)
+        ]
+    );
+
+    let sentinel = 0;
+    assert_eq!(
+        values(
+            grid.neighbours_or(Point(2, 2), Neighbourhood::VonNeumann, &sentinel)
+                .collect()
+        ),
+        vec![0, 0, 6, 8]
+    );
+    assert_eq!(grid.wrap(Point(-1, 4)), Point(2, 1));
+}
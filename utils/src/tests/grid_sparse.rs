@@ -0,0 +1,112 @@
+use {
+    crate::*,
+    std::collections::{BTreeMap, BTreeSet, HashMap},
+};
+
+fn sparse<S: Storage<char>>() -> SparseGrid<char, S> {
+    [(Point(2, 3), 'a'), (Point(-1, 5), 'b'), (Point(4, -2), 'c')]
+        .into_iter()
+        .collect()
+}
+
+#[test]
+fn test_bounds() {
+    let mut grid = SparseGrid::new();
+    assert_eq!(grid.bounds(), None);
+    grid.insert(Point(2, 3), ());
+    assert_eq!(grid.bounds(), Some((Point(2, 3), Point(2, 3))));
+
+    let mut grid = sparse::<BTreeMap<_, _>>();
+    assert_eq!(grid.min(), Some(Point(-1, -2)));
+    assert_eq!(grid.max(), Some(Point(4, 5)));
+    assert_eq!(grid.remove(&Point(0, 0)), None);
+    assert_eq!(grid.remove(&Point(4, -2)), Some('c'));
+    assert_eq!(grid.bounds(), Some((Point(-1, 3), Point(2, 5))));
+    grid.remove(&Point(-1, 5));
+    grid.remove(&Point(2, 3));
+    assert_eq!(grid.bounds(), None);
+    assert!(grid.is_empty());
+}
+
+#[test]
+fn test_contains() {
+    let grid = sparse::<HashMap<_, _>>();
+    assert!(grid.contains_point(&Point(2, 3)));
+    assert!(!grid.contains_point(&Point(0, 0)));
+    assert!(!grid.contains_point(&Point(9, 9)));
+}
+
+#[test]
+fn test_iter() {
+    let mut grid = sparse::<BTreeMap<_, _>>();
+    assert_eq!(
+        grid.keys().copied().collect::<Vec<_>>(),
+        [Point(-1, 5), Point(2, 3), Point(4, -2)]
+    );
+    grid.iter_mut()
+        .for_each(|(_, c)| *c = c.to_ascii_uppercase());
+    assert_eq!(grid.iter().map(|(_, c)| *c).collect::<String>(), "BAC");
+    assert_eq!(grid.into_iter().collect::<Vec<_>>()[0], (Point(-1, 5), 'B'));
+}
+
+#[test]
+fn test_range() {
+    let ordered = sparse::<BTreeMap<_, _>>();
+    let hashed: HashSparseGrid<char> = sparse();
+    for (min, max, expected) in [
+        (Point(0, 0), Point(4, 4), "a"),
+        (Point(-1, -2), Point(4, 5), "abc"),
+        (Point(-1, 4), Point(2, 5), "b"),
+        (Point(3, 3), Point(0, 0), ""),
+    ] {
+        let chars = |range: Vec<(&Point, &char)>| {
+            range
+                .into_iter()
+                .map(|(_, c)| *c)
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect::<String>()
+        };
+        assert_eq!(chars(ordered.range(min, max).collect()), expected);
+        assert_eq!(chars(hashed.range(min, max).collect()), expected);
+    }
+}
+
+#[test]
+fn test_increment() {
+    let mut grid = HashSparseGrid::with_capacity(2);
+    grid += Point(1, 1);
+    grid += Point(1, 1);
+    grid.increment(Point(0, 1));
+    assert_eq!(grid.get(&Point(1, 1)), Some(&2));
+    assert_eq!(grid.len(), 2);
+    assert_eq!(grid.bounds(), Some((Point(0, 1), Point(1, 1))));
+}
+
+#[test]
+fn test_to_grid() {
+    let grid = Grid::from(sparse::<BTreeMap<_, _>>());
+    assert_eq!(grid.origin(), Point(-1, -2));
+    assert_eq!((grid.cols(), grid.rows()), (6, 8));
+    assert_eq!(grid.get(grid.from_plane(Point(-1, 5))), Some(&'b'));
+    assert_eq!(grid.get(Point(0, 0)), Some(&'\0'));
+    assert_eq!(grid.to_plane(Point(5, 0)), Point(4, -2));
+
+    assert_eq!(Grid::from(SparseGrid::<usize>::new()).len(), 0);
+}
+
+#[test]
+fn test_from_grid() {
+    let sparse = sparse::<BTreeMap<_, _>>();
+    let grid = Grid::from(sparse.clone());
+    assert_eq!(grid.clone().into_sparse_skipping_default(), sparse);
+
+    let every: HashSparseGrid<char> = SparseGrid::from(grid);
+    assert_eq!(every.len(), 48);
+    assert_eq!(every.bounds(), sparse.bounds());
+
+    let cropped = Grid::from(vec![vec![1, 0, 2], vec![0, 3, 0]])
+        .crop(Point(1, 1), Point(2, 1))
+        .into_sparse_skipping_default::<BTreeMap<_, _>>();
+    assert_eq!(cropped.into_iter().collect::<Vec<_>>(), [(Point(1, 1), 3)]);
+}
@@ -0,0 +1,15 @@
+mod answer;
+mod answers;
+mod display;
+mod dot;
+mod grid;
+mod grid_sparse;
+mod ocr;
+mod point_n;
+mod record;
+mod region;
+mod render;
+mod search;
+mod step;
+mod timing;
+mod transform;
@@ -0,0 +1,70 @@
+use crate::*;
+
+const SMALL: &str = r#"
+####.#..#.###..####.###....##..##..#....
+#....#..#.#..#....#.#..#....#.#..#.#....
+###..####.#..#...#..#..#....#.#....#....
+#....#..#.###...#...###.....#.#.##.#....
+#....#..#.#....#....#....#..#.#..#.#....
+####.#..#.#....####.#.....##...###.####.
+"#;
+
+const LARGE: &str = r#"
+#....#..######
+##...#.......#
+##...#.......#
+#.#..#......#.
+#.#..#.....#..
+#..#.#....#...
+#..#.#...#....
+#...##..#.....
+#...##..#.....
+#....#..######
+"#;
+
+#[test]
+fn test_ocr() {
+    assert_eq!(ocr(SMALL).unwrap(), "EHPZPJGL");
+    assert_eq!(ocr(&SMALL.replace('.', " ")).unwrap(), "EHPZPJGL");
+    assert_eq!(ocr(LARGE).unwrap(), "NZ");
+    assert_eq!(
+        ocr(" ███ \n█    \n█    \n ██  \n   █ \n███  ").unwrap(),
+        "S"
+    );
+}
+
+#[test]
+fn test_ocr_grid() {
+    let mut grid = Grid::<bool>::new(4, 6);
+    for point in [
+        (0, 0),
+        (0, 1),
+        (0, 2),
+        (0, 3),
+        (0, 4),
+        (0, 5),
+        (1, 5),
+        (2, 5),
+        (3, 5),
+    ] {
+        grid.insert(point.into(), true);
+    }
+    assert_eq!(ocr_grid(&grid, |lit| *lit).unwrap(), "L");
+}
+
+#[test]
+fn test_errors() {
+    assert!(matches!(
+        ocr("\n\n#..#\n#..#\n####\n####\n#..#\n#..#"),
+        Err(Error::Parse {
+            line: 3,
+            column: 1,
+            ..
+        })
+    ));
+    assert!(matches!(
+        ocr("####\n#...\n###."),
+        Err(Error::Parse { line: 1, .. })
+    ));
+    assert!(ocr("....\n....").is_err());
+}
@@ -0,0 +1,73 @@
+use {crate::*, std::collections::BTreeSet};
+
+#[test]
+fn test_arithmetic() {
+    let mut point = Point3::from([1, -2, 3]);
+    point += PointN([1, 1, 1]);
+    assert_eq!(point, PointN([2, -1, 4]));
+    assert_eq!(point - PointN([2, -1, 4]), Point3::default());
+    assert_eq!(point + point, PointN([4, -2, 8]));
+    point[2] = 0;
+    assert_eq!(point[2], 0);
+
+    assert_eq!(PointN::from(Point(3, -4)), PointN([3, -4]));
+    assert_eq!(Point::from(PointN([3, -4])), Point(3, -4));
+}
+
+#[test]
+fn test_distances() {
+    let (a, b) = (Point4::from([0, 0, 0, 0]), Point4::from([1, -3, 2, 0]));
+    assert_eq!(a.orth_distance(&b), 6);
+    assert_eq!(a.distance(&b), 3);
+    assert_eq!(a.follow(&b), PointN([1, -1, 1, 0]));
+
+    // The same as on the plane
+    let (p, q) = (Point(2, 5), Point(-1, 1));
+    assert_eq!(
+        PointN::from(p).orth_distance(&q.into()),
+        p.orth_distance(&q)
+    );
+    assert_eq!(PointN::from(p).distance(&q.into()), p.distance(&q));
+}
+
+#[test]
+fn test_neighbours() {
+    let origin = Point3::default();
+    let orthogonal = origin
+        .neighbours(Neighbourhood::VonNeumann)
+        .collect::<BTreeSet<_>>();
+    assert_eq!(orthogonal.len(), 6);
+    assert!(orthogonal
+        .iter()
+        .all(|point| point.orth_distance(&origin) == 1));
+
+    let all = origin
+        .neighbours(Neighbourhood::Moore)
+        .collect::<BTreeSet<_>>();
+    assert_eq!(all.len(), 26);
+    assert!(all.iter().all(|point| point.distance(&origin) == 1));
+    assert_eq!(
+        Point4::default().neighbours(Neighbourhood::Moore).count(),
+        80
+    );
+
+    // Two steps away, chaining on the temporary neighbours
+    let reach = origin
+        .neighbours(Neighbourhood::VonNeumann)
+        .flat_map(|point| point.neighbours(Neighbourhood::VonNeumann))
+        .collect::<BTreeSet<_>>();
+    assert_eq!(reach.len(), 19);
+    assert!(reach.contains(&origin));
+
+    // The same as on the plane
+    let point = Point(4, -7);
+    for neighbourhood in [Neighbourhood::VonNeumann, Neighbourhood::Moore] {
+        assert_eq!(
+            PointN::from(point)
+                .neighbours(neighbourhood)
+                .map(Point::from)
+                .collect::<BTreeSet<_>>(),
+            neighbourhood.points(point).collect::<BTreeSet<_>>()
+        );
+    }
+}
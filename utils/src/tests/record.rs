@@ -0,0 +1,88 @@
+use {crate::*, std::time::Duration};
+
+fn frames() -> Vec<SparseGrid<char>> {
+    let mut grid = SparseGrid::new();
+    let mut frames = Vec::new();
+    for (x, c) in "abc".chars().enumerate() {
+        grid.insert(Point(x as isize, 0), c);
+        frames.push(grid.clone());
+    }
+    frames
+}
+
+#[test]
+fn test_recorder() {
+    let mut recorder = Recorder::new(Duration::from_millis(100));
+    assert!(recorder.is_empty());
+    for grid in frames() {
+        recorder.record(grid.display(|c| *c.unwrap_or(&'.')).to_string());
+    }
+    assert_eq!(recorder.len(), 3);
+    assert_eq!(recorder.frames()[2], "abc\n");
+}
+
+#[test]
+fn test_gif() {
+    let mut recorder = Recorder::new(Duration::from_millis(250));
+    for grid in frames() {
+        recorder.record(
+            grid.image(|c| c.map_or(Rgb::BLACK, |_| Rgb::GREEN))
+                .scale(2),
+        );
+    }
+    // Moving left, beyond the first frames
+    let mut grid = SparseGrid::new();
+    grid.insert(Point(-1, 1), 'd');
+    recorder.record(grid.image(|c| c.map_or(Rgb::BLACK, |_| Rgb::RED)).scale(2));
+    let mut gif = Vec::new();
+    recorder.write_gif(Rgb::WHITE, &mut gif).unwrap();
+
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::RGBA);
+    let mut decoder = options.read_info(gif.as_slice()).unwrap();
+    assert_eq!((decoder.width(), decoder.height()), (8, 4));
+    let mut frames = Vec::new();
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        assert_eq!(frame.delay, 25);
+        assert_eq!((frame.width, frame.height), (8, 4));
+        frames.push(frame.buffer.to_vec());
+    }
+    assert_eq!(frames.len(), 4);
+    // The first point of the first frame, at the same place in the last one
+    let pixel = |frame: &[u8], x: usize, y: usize| frame[(y * 8 + x) * 4..][..3].to_vec();
+    assert_eq!(pixel(&frames[0], 2, 0), [133, 153, 0]);
+    assert_eq!(pixel(&frames[0], 0, 0), [255, 255, 255]);
+    assert_eq!(pixel(&frames[3], 2, 0), [255, 255, 255]);
+    assert_eq!(pixel(&frames[3], 0, 2), [220, 50, 47]);
+}
+
+#[test]
+fn test_gif_delay() {
+    let mut recorder = Recorder::new(Duration::from_secs(1000));
+    recorder.record(Image::new(Point(0, 0), Point(0, 0), |_| Rgb::BLACK));
+    let err = recorder.write_gif(Rgb::BLACK, Vec::new()).unwrap_err();
+    assert_eq!(err.to_string(), "delay too long for a GIF");
+}
+
+#[test]
+fn test_asciicast() {
+    let mut recorder = Recorder::new(Duration::from_millis(500));
+    recorder.record("#.\n.#\n".to_string());
+    recorder.record("...\n".to_string());
+    let mut cast = Vec::new();
+    recorder.write_asciicast(&mut cast).unwrap();
+
+    let lines = String::from_utf8(cast)
+        .unwrap()
+        .lines()
+        .map(|line| serde_json::from_str::<serde_json::Value>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        lines,
+        [
+            serde_json::json!({ "version": 2, "width": 3, "height": 2 }),
+            serde_json::json!([0.0, "o", "\x1b[H\x1b[2J#.\r\n.#\r\n"]),
+            serde_json::json!([0.5, "o", "\x1b[H\x1b[2J...\r\n"]),
+        ]
+    );
+}
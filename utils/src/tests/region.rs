@@ -0,0 +1,105 @@
+use crate::*;
+
+const GARDEN: &str = "
+AAAA
+BBCD
+BBCC
+EEEC
+";
+
+const HOLES: &str = "
+OOOOO
+OXOXO
+OOOOO
+OXOXO
+OOOOO
+";
+
+fn garden(s: &str) -> Grid<char> {
+    Grid::parse(s, Ok::<_, String>).unwrap()
+}
+
+#[test]
+fn test_flood_fill() {
+    let grid = garden(GARDEN);
+    let region = grid.flood_fill(Point(2, 1), |plant| *plant == 'C');
+    assert_eq!(
+        region.points().copied().collect::<Vec<_>>(),
+        vec![Point(2, 1), Point(2, 2), Point(3, 2), Point(3, 3)]
+    );
+    assert_eq!(
+        grid.flood_fill(Point(0, 0), |plant| *plant == 'C').area(),
+        0
+    );
+    assert_eq!(grid.flood_fill(Point(9, 9), |_| true).area(), 0);
+    assert_eq!(grid.flood_fill(Point(0, 0), |_| true).area(), 16);
+}
+
+#[test]
+fn test_components() {
+    let grid = garden(GARDEN);
+    let components = grid.components(|a, b| a == b);
+    let stats = components
+        .regions
+        .iter()
+        .map(|region| (region.area(), region.perimeter(), region.sides()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        stats,
+        vec![(4, 10, 4), (4, 8, 4), (4, 10, 8), (1, 4, 4), (3, 8, 4)]
+    );
+    assert_eq!(components.labels.get(Point(3, 1)), Some(&3));
+    assert_eq!(components.labels.get(Point(3, 3)), Some(&2));
+}
+
+#[test]
+fn test_holes() {
+    let grid = garden(HOLES);
+    let components = grid.components(|a, b| a == b);
+    assert_eq!(components.regions.len(), 5);
+    let outer = &components.regions[0];
+    assert_eq!(
+        (outer.area(), outer.perimeter(), outer.sides()),
+        (21, 36, 20)
+    );
+    assert!(!grid.is_enclosed(outer));
+    assert!(components.regions[1..]
+        .iter()
+        .all(|hole| grid.is_enclosed(hole) && hole.sides() == 4));
+}
+
+// Every cell in exactly one region, the one its label points to
+fn assert_partition<T>(grid: &Grid<T>, components: &Components) {
+    assert!(!components.labels.values().contains(&usize::MAX));
+    assert_eq!(
+        components.regions.iter().map(Region::area).sum::<usize>(),
+        grid.len()
+    );
+    for (label, region) in components.regions.iter().enumerate() {
+        assert!(region.area() > 0);
+        assert!(region
+            .points()
+            .all(|point| components.labels.get(*point) == Some(&label)));
+    }
+}
+
+#[test]
+fn test_components_non_transitive() {
+    // 1 and 3 are both close to 2, but not to each other
+    let grid = Grid::from(vec![vec![1, 2, 3], vec![3, 9, 1]]);
+    let components = grid.components(|a: &i32, b| a.abs_diff(*b) <= 1);
+    assert_partition(&grid, &components);
+    assert_eq!(components.regions.len(), 4);
+    assert_eq!(components.regions[0].area(), 3);
+}
+
+#[test]
+fn test_components_non_reflexive() {
+    let grid = garden("\n.#.\n##.\n");
+    let components = grid.components(|a, b| a == b && *a != '#');
+    assert_partition(&grid, &components);
+    // Each wall alone, the dots in two regions
+    assert_eq!(components.regions.len(), 5);
+    assert_eq!(components.labels.get(Point(0, 0)), Some(&0));
+    assert_eq!(components.regions[2].area(), 2);
+}
@@ -0,0 +1,93 @@
+use crate::*;
+
+fn image() -> Image {
+    let grid = Grid::from(vec![vec![0, 1], vec![2, 3]]);
+    grid.image(|height| Rgb::BLACK.mix(Rgb::WHITE, *height as f64 / 3.))
+}
+
+#[test]
+fn test_colours() {
+    assert_eq!(Rgb::BLACK.mix(Rgb::WHITE, 0.5), Rgb(128, 128, 128));
+    assert_eq!(Rgb::BLACK.mix(Rgb::WHITE, 2.), Rgb::WHITE);
+    assert_eq!(Rgb::heat(0., 0., 9.), Rgb(30, 20, 80));
+    assert_eq!(Rgb::heat(9., 0., 9.), Rgb(250, 230, 80));
+    assert_eq!(Rgb::heat(1., 1., 1.), Rgb(30, 20, 80));
+}
+
+#[test]
+fn test_svg() {
+    let svg = image().scale(10).to_svg();
+    assert!(svg.starts_with(r#"<svg xmlns="http://www.w3.org/2000/svg" width="20" height="20""#));
+    assert!(svg.contains(r##"<rect x="10" y="10" width="10" height="10" fill="#ffffff"/>"##));
+    assert_eq!(svg.matches("<rect").count(), 4);
+
+    // The last row on top
+    let svg = image().y_up().to_svg();
+    assert!(svg.contains(r##"<rect x="1" y="0" width="1" height="1" fill="#ffffff"/>"##));
+}
+
+#[test]
+fn test_ppm() {
+    let mut ppm = Vec::new();
+    image().scale(2).write_ppm(&mut ppm).unwrap();
+    let header = b"P6\n4 4\n255\n";
+    assert_eq!(&ppm[..header.len()], header);
+    assert_eq!(ppm.len(), header.len() + 4 * 4 * 3);
+    // Second row of pixels, still the first row of points
+    assert_eq!(
+        &ppm[header.len() + 12..header.len() + 24],
+        &[0, 0, 0, 0, 0, 0, 85, 85, 85, 85, 85, 85]
+    );
+}
+
+#[test]
+fn test_png() {
+    let mut png = Vec::new();
+    image().write_png(&mut png).unwrap();
+    let mut reader = png::Decoder::new(png.as_slice()).read_info().unwrap();
+    let mut data = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut data).unwrap();
+    assert_eq!((info.width, info.height), (2, 2));
+    assert_eq!(
+        &data[..info.buffer_size()],
+        &[0, 0, 0, 85, 85, 85, 170, 170, 170, 255, 255, 255]
+    );
+}
+
+#[test]
+fn test_y_up() {
+    let flipped = image().scale(2).y_up();
+    assert_eq!(flipped.size(), (4, 4));
+    assert_eq!(
+        flipped.rgb()[..12],
+        [170, 170, 170, 170, 170, 170, 255, 255, 255, 255, 255, 255]
+    );
+    assert_eq!(flipped.rgb()[36..], image().scale(2).rgb()[..12]);
+}
+
+#[test]
+fn test_sparse() {
+    let mut trail = SparseGrid::new();
+    trail.insert(Point(-1, 0), ());
+    trail.insert(Point(1, 1), ());
+    let image = trail.image(|visited| {
+        if visited.is_some() {
+            Rgb::RED
+        } else {
+            Rgb::WHITE
+        }
+    });
+    assert_eq!(image.size(), (3, 2));
+}
+
+#[test]
+fn test_reframe() {
+    assert_eq!(image().bounds(), Some((Point(0, 0), Point(1, 1))));
+    let reframed = image().reframe(Point(-1, 1), Point(1, 1), Rgb::RED);
+    assert_eq!(reframed.bounds(), Some((Point(-1, 1), Point(1, 1))));
+    assert_eq!(reframed.rgb(), [220, 50, 47, 170, 170, 170, 255, 255, 255]);
+    assert_eq!(
+        Image::new(Point(0, 0), Point(-1, -1), |_| Rgb::RED).bounds(),
+        None
+    );
+}
@@ -0,0 +1,126 @@
+use crate::*;
+
+const MAZE: &str = "
+S.#....
+.##.##.
+...#..G
+.#...#.
+";
+
+fn maze() -> (Grid<char>, Point, Point) {
+    let grid = Grid::parse(MAZE, Ok::<_, String>).unwrap();
+    let find = |c| {
+        grid.points()
+            .into_iter()
+            .find(|point| grid.get(*point) == Some(&c))
+    };
+    let (start, goal) = (find('S').unwrap(), find('G').unwrap());
+    (grid, start, goal)
+}
+
+fn open(grid: &Grid<char>, point: Point) -> impl Iterator<Item = Point> + '_ {
+    grid.neighbours(point, Neighbourhood::VonNeumann)
+        .filter(|(_, c)| **c != '#')
+        .map(|(point, _)| point)
+}
+
+#[test]
+fn test_bfs() {
+    let (grid, start, goal) = maze();
+    let path = bfs(start, |point| open(&grid, *point), |point| *point == goal).unwrap();
+    assert_eq!(path.cost, 10);
+    assert_eq!(path.states.len(), 11);
+    assert_eq!(path.states.first(), Some(&start));
+    assert_eq!(path.states.last(), Some(&goal));
+    assert!(path
+        .states
+        .windows(2)
+        .all(|pair| pair[0].orth_distance(&pair[1]) == 1));
+
+    assert_eq!(bfs(start, |point| open(&grid, *point), |_| false), None);
+    assert_eq!(
+        bfs(start, |point| open(&grid, *point), |point| *point == start),
+        Some(Path {
+            cost: 0,
+            states: vec![start]
+        })
+    );
+}
+
+#[test]
+fn test_dijkstra() {
+    // Moving right is cheap, anything else is expensive
+    let (grid, start, goal) = maze();
+    let successors = |point: &Point| {
+        let from = *point;
+        open(&grid, from)
+            .map(move |to| (to, if to.0 > from.0 { 1 } else { 3 }))
+            .collect::<Vec<_>>()
+    };
+    let path = dijkstra(start, successors, |point| *point == goal).unwrap();
+    assert_eq!(path.cost, 6 + 4 * 3);
+    assert_eq!(path.states.len(), 11);
+}
+
+#[test]
+fn test_astar() {
+    // With no wall in the way, every cell between the corners is as promising,
+    // A* goes deep to the goal while Dijkstra's algorithm spreads out
+    let grid = Grid::parse(&".........\n".repeat(9), Ok::<_, String>).unwrap();
+    let (start, goal) = (Point(0, 0), Point(8, 8));
+    let successors = |point: &Point| {
+        open(&grid, *point)
+            .map(|point| (point, 1))
+            .collect::<Vec<_>>()
+    };
+    // Each state popped from the queue is checked against the goal once
+    let mut expanded = [0, 0];
+    let mut is_goal = |search: usize, point: &Point| {
+        expanded[search] += 1;
+        *point == goal
+    };
+    let path = astar(
+        start,
+        successors,
+        |point| is_goal(0, point),
+        |point| point.orth_distance(&goal),
+    )
+    .unwrap();
+    let dijkstra = dijkstra(start, successors, |point| is_goal(1, point)).unwrap();
+    assert_eq!(path.cost, 16);
+    assert_eq!(path.cost, dijkstra.cost);
+    assert_eq!(expanded[0], path.cost + 1);
+    assert!(expanded[0] < expanded[1], "{:?}", expanded);
+}
+
+#[test]
+fn test_many_starts() {
+    let (grid, start, goal) = maze();
+    let starts = [start, Point(3, 0)];
+    let path = bfs_from(starts, |point| open(&grid, *point), |point| *point == goal).unwrap();
+    assert_eq!(path.cost, 5);
+    assert_eq!(path.states[0], Point(3, 0));
+
+    let weighted = |point: &Point| open(&grid, *point).map(|point| (point, 2));
+    let path = dijkstra_from(starts, weighted, |point| *point == goal).unwrap();
+    assert_eq!(path.cost, 10);
+}
+
+#[test]
+fn test_distances() {
+    let (grid, start, goal) = maze();
+    let distances = grid.distances([start, goal], |point| open(&grid, *point));
+    assert_eq!(distances.get(start), Some(&Some(0)));
+    assert_eq!(distances.get(Point(4, 3)), Some(&Some(3)));
+    assert_eq!(distances.get(Point(2, 3)), Some(&Some(5)));
+    assert_eq!(distances.get(Point(2, 0)), Some(&None));
+    assert_eq!(distances.get(Point(3, 1)), Some(&Some(6)));
+
+    let weighted =
+        grid.weighted_distances([goal], |point| open(&grid, *point).map(|point| (point, 2)));
+    assert_eq!(weighted.get(start), Some(&Some(20)));
+    assert_eq!(
+        weighted.values().iter().flatten().count(),
+        distances.values().iter().flatten().count()
+    );
+}
@@ -0,0 +1,57 @@
+use crate::*;
+
+#[test]
+fn test_turns() {
+    assert_eq!(Direction::North.turn_right(), Direction::East);
+    assert_eq!(Direction::North.turn_left(), Direction::West);
+    assert_eq!(Direction::West.turn_right(), Direction::North);
+    assert_eq!(Direction::NorthEast.turn(-3), Direction::West);
+    assert_eq!(Direction::South.turn(9), Direction::SouthWest);
+    assert_eq!(Direction::SouthEast.opposite(), Direction::NorthWest);
+    for direction in DIRECTIONS {
+        assert_eq!(direction.turn(8), direction);
+        assert_eq!(direction.opposite().opposite(), direction);
+        assert_eq!(direction.turn_right().turn_left(), direction);
+        assert_eq!(direction.unit() + direction.opposite().unit(), Point(0, 0));
+        assert_eq!(direction.unit().distance(&Point(0, 0)), 1);
+        assert_eq!(
+            direction.is_orthogonal(),
+            direction.unit().orth_distance(&Point(0, 0)) == 1
+        );
+    }
+}
+
+#[test]
+fn test_parse() {
+    for (chars, direction) in [
+        ("UN^", Direction::North),
+        ("RE>", Direction::East),
+        ("DSv", Direction::South),
+        ("LW<", Direction::West),
+    ] {
+        for c in chars.chars() {
+            assert_eq!(Direction::try_from(c), Ok(direction));
+            assert_eq!(c.to_string().parse(), Ok(direction));
+        }
+    }
+    assert!(Direction::try_from('x').is_err());
+    assert!("UU".parse::<Direction>().is_err());
+    assert!("".parse::<Direction>().is_err());
+}
+
+#[test]
+fn test_neighbours() {
+    let point = Point(1, 1);
+    assert_eq!(
+        point.neighbours(Direction::East)[..3],
+        [Point(2, 1), Point(2, 0), Point(1, 0)]
+    );
+    assert_eq!(
+        point.orth_neighbours(Direction::South),
+        [Point(1, 0), Point(0, 1), Point(1, 2), Point(2, 1)]
+    );
+    assert_eq!(
+        point.orth_neighbours(Direction::NorthWest),
+        [Point(0, 2), Point(2, 2), Point(2, 0), Point(0, 0)]
+    );
+}
@@ -0,0 +1,39 @@
+use {crate::*, std::time::Duration};
+
+#[test]
+fn test_stats() {
+    let ms = Duration::from_millis;
+    assert_eq!(Stats::new(vec![]), None);
+    assert_eq!(
+        Stats::new(vec![ms(3), ms(1), ms(2)]),
+        Some(Stats {
+            min: ms(1),
+            median: ms(2),
+            max: ms(3)
+        })
+    );
+    assert_eq!(
+        Stats::new(vec![ms(4), ms(1), ms(2), ms(8)]).unwrap().median,
+        ms(3)
+    );
+}
+
+#[test]
+fn test_bench() {
+    let timings = |parse, part1, part2| Timings {
+        parse: Duration::from_micros(parse),
+        part1: Duration::from_micros(part1),
+        part2: Duration::from_micros(part2),
+    };
+    let bench = Bench::new(&[
+        timings(1, 10, 100),
+        timings(2, 30, 300),
+        timings(3, 20, 200),
+    ])
+    .unwrap();
+    assert_eq!(bench.runs, 3);
+    assert_eq!(bench.parse.median, Duration::from_micros(2));
+    assert_eq!(bench.part1.max, Duration::from_micros(30));
+    assert_eq!(bench.part2.min, Duration::from_micros(100));
+    assert_eq!(Bench::new(&[]), None);
+}
@@ -0,0 +1,103 @@
+use crate::*;
+
+// 1 2 3
+// 4 5 6
+fn grid() -> Grid<i32> {
+    Grid::from(vec![vec![1, 2, 3], vec![4, 5, 6]])
+}
+
+fn rows<T: Clone>(grid: &Grid<T>) -> Vec<Vec<T>> {
+    grid.iter_rows().map(<[T]>::to_vec).collect()
+}
+
+#[test]
+fn test_transpose() {
+    let transposed = grid().transpose();
+    assert_eq!(rows(&transposed), vec![vec![1, 4], vec![2, 5], vec![3, 6]]);
+    assert_eq!(transposed.max(), Point(1, 2));
+    assert_eq!(transposed.transpose(), grid());
+}
+
+#[test]
+fn test_rotate() {
+    assert_eq!(
+        rows(&grid().rotate_cw()),
+        vec![vec![4, 1], vec![5, 2], vec![6, 3]]
+    );
+    assert_eq!(
+        rows(&grid().rotate_ccw()),
+        vec![vec![3, 6], vec![2, 5], vec![1, 4]]
+    );
+    assert_eq!(grid().rotate_cw().rotate_ccw(), grid());
+    assert_eq!(grid().rotate_cw().rotate_cw(), grid().flip_h().flip_v());
+}
+
+#[test]
+fn test_flip() {
+    assert_eq!(rows(&grid().flip_h()), vec![vec![3, 2, 1], vec![6, 5, 4]]);
+    assert_eq!(rows(&grid().flip_v()), vec![vec![4, 5, 6], vec![1, 2, 3]]);
+}
+
+#[test]
+fn test_crop_and_view() {
+    let grid = grid();
+    assert_eq!(
+        rows(&grid.crop(Point(1, 0), Point(2, 1))),
+        vec![vec![2, 3], vec![5, 6]]
+    );
+    assert_eq!(
+        rows(&grid.crop(Point(-5, 1), Point(9, 9))),
+        vec![vec![4, 5, 6]]
+    );
+    assert!(grid.crop(Point(3, 0), Point(4, 1)).is_empty());
+
+    let view = grid.view(Point(1, 0), Point(2, 1));
+    assert_eq!((view.cols(), view.rows()), (2, 2));
+    assert_eq!(view.offset(), Point(1, 0));
+    assert_eq!(view.get(Point(0, 1)), Some(&5));
+    assert_eq!(view.get(Point(2, 0)), None);
+    assert_eq!(
+        view.iter_rows().collect::<Vec<_>>(),
+        vec![&[2, 3][..], &[5, 6][..]]
+    );
+}
+
+#[test]
+fn test_pad_and_tile() {
+    assert_eq!(
+        rows(&grid().pad(1, 0)),
+        vec![
+            vec![0, 0, 0, 0, 0],
+            vec![0, 1, 2, 3, 0],
+            vec![0, 4, 5, 6, 0],
+            vec![0, 0, 0, 0, 0],
+        ]
+    );
+    assert_eq!(
+        rows(&grid().tile(2, 2)),
+        vec![
+            vec![1, 2, 3, 1, 2, 3],
+            vec![4, 5, 6, 4, 5, 6],
+            vec![1, 2, 3, 1, 2, 3],
+            vec![4, 5, 6, 4, 5, 6],
+        ]
+    );
+}
+
+#[test]
+fn test_origin() {
+    let grid = grid().with_origin(Point(-2, 5));
+    assert_eq!(grid.transpose().origin(), Point(5, -2));
+    assert_eq!(grid.transpose().transpose(), grid);
+    for transformed in [
+        grid.rotate_cw(),
+        grid.rotate_ccw(),
+        grid.flip_h(),
+        grid.flip_v(),
+        grid.tile(2, 3),
+    ] {
+        assert_eq!(transformed.origin(), Point(-2, 5));
+    }
+    assert_eq!(grid.pad(1, 0).origin(), Point(-3, 4));
+    assert_eq!(grid.crop(Point(1, 1), Point(2, 1)).origin(), Point(-1, 6));
+}
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Duration,
+}
+
+impl Timings {
+    pub fn total(&self) -> Duration {
+        self.parse + self.part1 + self.part2
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn new(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort();
+        let median = match samples.len() {
+            0 => return None,
+            n if n % 2 == 0 => (samples[n / 2 - 1] + samples[n / 2]) / 2,
+            n => samples[n / 2],
+        };
+        Some(Self {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        })
+    }
+}
+
+impl std::fmt::Display for Stats {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "min {:?}, median {:?}, max {:?}",
+            self.min, self.median, self.max
+        )
+    }
+}
+
+// Summarizes repeated runs, stage by stage
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Bench {
+    pub runs: usize,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+impl Bench {
+    pub fn new(timings: &[Timings]) -> Option<Self> {
+        let stats =
+            |stage: fn(&Timings) -> Duration| Stats::new(timings.iter().map(stage).collect());
+        Some(Self {
+            runs: timings.len(),
+            parse: stats(|timings| timings.parse)?,
+            part1: stats(|timings| timings.part1)?,
+            part2: stats(|timings| timings.part2)?,
+        })
+    }
+}
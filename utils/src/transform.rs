@@ -0,0 +1,143 @@
+use super::*;
+
+// Transformations are described as the grid is displayed, its first row on top.
+// They keep the origin, the top left cell staying where it was on the plane,
+// except for `transpose` which mirrors the plane along its diagonal too.
+impl<T: Clone> Grid<T> {
+    fn at(&self, col: isize, row: isize) -> T {
+        self.values[self.index(Point(col, row))].clone()
+    }
+
+    pub fn transpose(&self) -> Self {
+        Self::from_fn(self.rows(), self.cols(), |Point(col, row)| {
+            self.at(row, col)
+        })
+        .with_origin(Point(self.origin.1, self.origin.0))
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        let last_row = self.max.1;
+        Self::from_fn(self.rows(), self.cols(), |Point(col, row)| {
+            self.at(row, last_row - col)
+        })
+        .with_origin(self.origin)
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        let last_col = self.max.0;
+        Self::from_fn(self.rows(), self.cols(), |Point(col, row)| {
+            self.at(last_col - row, col)
+        })
+        .with_origin(self.origin)
+    }
+
+    // Mirrors the columns, left becoming right
+    pub fn flip_h(&self) -> Self {
+        let last_col = self.max.0;
+        Self::from_fn(self.cols(), self.rows(), |Point(col, row)| {
+            self.at(last_col - col, row)
+        })
+        .with_origin(self.origin)
+    }
+
+    // Mirrors the rows, top becoming bottom
+    pub fn flip_v(&self) -> Self {
+        let last_row = self.max.1;
+        Self::from_fn(self.cols(), self.rows(), |Point(col, row)| {
+            self.at(col, last_row - row)
+        })
+        .with_origin(self.origin)
+    }
+
+    // The cells from `min` to `max` included, within the grid
+    pub fn crop(&self, min: Point, max: Point) -> Self {
+        self.view(min, max).to_grid()
+    }
+
+    // Surrounds the grid with `n` rows and columns of `value` on each side
+    pub fn pad(&self, n: usize, value: T) -> Self {
+        let offset = Point(n as isize, n as isize);
+        Self::from_fn(self.cols() + 2 * n, self.rows() + 2 * n, |point| {
+            self.get(point - offset).unwrap_or(&value).clone()
+        })
+        .with_origin(self.origin - offset)
+    }
+
+    // Repeats the grid `nx` times horizontally and `ny` times vertically
+    pub fn tile(&self, nx: usize, ny: usize) -> Self {
+        let (cols, rows) = (self.cols() as isize, self.rows() as isize);
+        Self::from_fn(self.cols() * nx, self.rows() * ny, |Point(col, row)| {
+            self.at(col % cols, row % rows)
+        })
+        .with_origin(self.origin)
+    }
+}
+
+impl<T> Grid<T> {
+    // Borrows the cells from `min` to `max` included, within the grid
+    pub fn view(
+        &self,
+        Point(min_col, min_row): Point,
+        Point(max_col, max_row): Point,
+    ) -> SubGrid<'_, T> {
+        let min = Point(min_col.max(0), min_row.max(0));
+        let max = Point(max_col.min(self.max.0), max_row.min(self.max.1));
+        SubGrid {
+            grid: self,
+            min,
+            max: Point(max.0.max(min.0 - 1), max.1.max(min.1 - 1)),
+        }
+    }
+}
+
+// A rectangle of a grid, whose points are relative to its top left corner
+#[derive(Debug, Clone, Copy)]
+pub struct SubGrid<'a, T> {
+    grid: &'a Grid<T>,
+    min: Point,
+    max: Point,
+}
+
+impl<'a, T> SubGrid<'a, T> {
+    // Where the view starts in the underlying grid
+    pub fn offset(&self) -> Point {
+        self.min
+    }
+
+    pub fn cols(&self) -> usize {
+        (self.max.0 - self.min.0 + 1) as usize
+    }
+
+    pub fn rows(&self) -> usize {
+        (self.max.1 - self.min.1 + 1) as usize
+    }
+
+    pub fn contains(&self, Point(col, row): &Point) -> bool {
+        (*col as usize) < self.cols() && (*row as usize) < self.rows()
+    }
+
+    pub fn get(&self, point: Point) -> Option<&'a T> {
+        self.contains(&point)
+            .then(|| self.grid.get(point + self.min))
+            .flatten()
+    }
+
+    // Each row is a slice of the underlying grid
+    pub fn iter_rows(&self) -> impl Iterator<Item = &'a [T]> + '_ {
+        let grid = self.grid;
+        (self.min.1..=self.max.1).filter_map(move |row| {
+            let start = grid.index(Point(self.min.0, row));
+            grid.values.get(start..start + self.cols())
+        })
+    }
+
+    pub fn to_grid(&self) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::from_fn(self.cols(), self.rows(), |point| {
+            self.grid.at(point.0 + self.min.0, point.1 + self.min.1)
+        })
+        .with_origin(self.grid.to_plane(self.min))
+    }
+}